@@ -0,0 +1,182 @@
+//! Custom derive for the `Accord` trait.
+//!
+//! Every field annotated with `#[accord(...)]` is validated by the listed validators and any
+//! errors are tagged with the name of the field:
+//!
+//! ```ignore
+//! #[macro_use]
+//! extern crate accord;
+//! #[macro_use]
+//! extern crate accord_derive;
+//!
+//! use accord::validators::{length, contains, range};
+//!
+//! #[derive(Accord)]
+//! struct Account {
+//!     #[accord(length(1, 64))]
+//!     pub name: String,
+//!     #[accord(length(5, 64), contains("@"), contains("."))]
+//!     pub email: String,
+//!     #[accord(range(12, 127))]
+//!     pub age: i8,
+//! }
+//! ```
+//!
+//! The validators are expanded as-is into a `rules!` invocation, so they must be in scope
+//! where the struct is defined. Fields that implement `Accord` themselves can be validated with
+//! `#[accord(nested())]`.
+//!
+//! Validators listed in an `#[accord(...)]` attribute on the struct itself receive the whole
+//! struct, which is useful for rules involving more than one field. They run after the
+//! validators of the fields and tag their errors themselves.
+//!
+//! With `#[accord(context = Type)]` on the struct, `AccordWith<Type>` is implemented instead of
+//! `Accord`, and the validators can use the context through `ctx`:
+//!
+//! ```ignore
+//! #[derive(Accord)]
+//! #[accord(context = Tenant)]
+//! struct OrderLine {
+//!     pub sku: String,
+//!     #[accord(range(1, ctx.stock(&self.sku)))]
+//!     pub quantity: u32,
+//! }
+//! ```
+//!
+//! The generated `validate_with_settings` honours the given `Settings` while running the
+//! validators, so it can stop early instead of running all of them.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::parse::ParseStream;
+use syn::{Attribute, Data, DeriveInput, Expr, Fields, Ident, Token, Type};
+
+#[proc_macro_derive(Accord, attributes(accord))]
+pub fn derive_accord(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match input.data {
+        Data::Struct(ref data) => {
+            match data.fields {
+                Fields::Named(ref fields) => &fields.named,
+                _ => {
+                    return Err(syn::Error::new_spanned(&input.ident,
+                                                       "#[derive(Accord)] requires named fields"))
+                }
+            }
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(&input.ident,
+                                               "#[derive(Accord)] can only be used on structs"))
+        }
+    };
+
+    let mut rules = Vec::new();
+    for field in fields {
+        let validators = validators(&field.attrs)?;
+        if validators.is_empty() {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        let tag = ident.unraw().to_string();
+        rules.push(quote! { #tag => self.#ident => [#(#validators),*] });
+    }
+
+    let mut context = None;
+    let mut attrs = Vec::new();
+    for attr in &input.attrs {
+        match context_type(attr)? {
+            Some(ty) => context = Some(ty),
+            None => attrs.push(attr.clone()),
+        }
+    }
+
+    let validators = validators(&attrs)?;
+    if !validators.is_empty() {
+        rules.push(quote! { self => [#(#validators),*] });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    if let Some(context) = context {
+        let body = if rules.is_empty() {
+            quote! { Ok(()) }
+        } else {
+            quote! { ::accord::rules!{ #(#rules),* } }
+        };
+        return Ok(quote! {
+            impl #impl_generics ::accord::AccordWith<#context> for #name #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn validate_with(&self, ctx: &#context) -> ::accord::Result {
+                    #body
+                }
+            }
+        });
+    }
+
+    if rules.is_empty() {
+        return Ok(quote! {
+            impl #impl_generics ::accord::Accord for #name #ty_generics #where_clause {
+                fn validate(&self) -> ::accord::Result {
+                    Ok(())
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::accord::Accord for #name #ty_generics #where_clause {
+            fn validate(&self) -> ::accord::Result {
+                ::accord::rules!{ #(#rules),* }
+            }
+
+            fn validate_with_settings(&self, settings: &::accord::Settings) -> ::accord::Result {
+                ::accord::rules!{ settings; #(#rules),* }
+            }
+        }
+    })
+}
+
+/// Returns the type given by `attr` if it is an `#[accord(context = Type)]` attribute.
+fn context_type(attr: &Attribute) -> syn::Result<Option<Type>> {
+    if !attr.path().is_ident("accord") {
+        return Ok(None);
+    }
+    attr.parse_args_with(|input: ParseStream| {
+        let is_context = input.peek2(Token![=]) && !input.peek2(Token![==]) &&
+                         input.fork().parse::<Ident>().is_ok_and(|ident| ident == "context");
+        if !is_context {
+            input.parse::<TokenStream2>()?;
+            return Ok(None);
+        }
+        input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        input.parse().map(Some)
+    })
+}
+
+/// Collects the validators from every `#[accord(...)]` attribute in `attrs`.
+fn validators(attrs: &[Attribute]) -> syn::Result<Vec<Expr>> {
+    let mut validators = Vec::new();
+    for attr in attrs {
+        if !attr.path().is_ident("accord") {
+            continue;
+        }
+        let list = attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+        validators.extend(list);
+    }
+    Ok(validators)
+}
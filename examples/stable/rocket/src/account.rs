@@ -0,0 +1,20 @@
+use accord::{Accord, Result as AccordResult};
+use accord::validators::{length, contains, range};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Account {
+    pub name: String,
+    pub email: String,
+    pub age: i8,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [length(1, 64)],
+            "email" => self.email => [length(5, 64), contains("@"), contains(".")],
+            "age" => self.age => [range(12, 127)]
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate rocket;
+
+mod account;
+
+use accord::rocket::{Validated, ValidationError};
+use account::Account;
+use rocket::serde::json::Json;
+
+#[post("/", data = "<account>")]
+fn json(account: Result<Validated<Json<Account>>, ValidationError>)
+        -> Result<Json<Account>, ValidationError> {
+    account.map(|Validated(account)| account)
+}
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build().mount("/", routes![json])
+}
@@ -0,0 +1,183 @@
+//! Validation of request data in [actix-web](https://docs.rs/actix-web).
+//!
+//! `Validated<Json<T>>`, `Validated<Query<T>>` and `Validated<Form<T>>` are extractors that
+//! extract like `Json<T>`, `Query<T>` and `Form<T>` and then validate the value. Data that does
+//! not validate is rejected with `422 Unprocessable Entity` and the serialized `MultipleError`
+//! as the body. Data that cannot be deserialized is rejected with the status the extractor would
+//! have used, and its error in the same shape, tagged with an empty tag.
+//!
+//! The response can be replaced by adding a `ValidationConfig` with an error handler as app
+//! data:
+//!
+//! ```
+//! # extern crate accord;
+//! # extern crate actix_web;
+//! use accord::actix::{Validated, ValidationConfig};
+//! use accord::ProblemDetails;
+//! use actix_web::{error, web, App, HttpResponse};
+//! # use accord::{Accord, Result as AccordResult};
+//! # #[derive(serde_derive::Deserialize)]
+//! # struct Account { name: String }
+//! # impl Accord for Account {
+//! #     fn validate(&self) -> AccordResult { Ok(()) }
+//! # }
+//!
+//! async fn create(Validated(web::Json(account)): Validated<web::Json<Account>>) -> String {
+//!     account.name
+//! }
+//!
+//! # fn main() {
+//! let app = App::new()
+//!     .app_data(ValidationConfig::default().error_handler(|err, _req| {
+//!         let response = HttpResponse::build(err.status)
+//!             .content_type(accord::problem::CONTENT_TYPE)
+//!             .json(ProblemDetails::from(&err.error));
+//!         error::InternalError::from_response(err, response).into()
+//!     }))
+//!     .route("/accounts", web::post().to(create));
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use ::actix_web::dev::Payload;
+use ::actix_web::error::{JsonPayloadError, UrlencodedError};
+use ::actix_web::http::StatusCode;
+use ::actix_web::web::{Form, Json, Query};
+use ::actix_web::{Error, FromRequest, HttpRequest, HttpResponse, ResponseError};
+use futures::future::{self, LocalBoxFuture};
+use serde::de::DeserializeOwned;
+
+use crate::{Accord, MultipleError};
+
+/// Extractor running `Accord::validate` on the extracted value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Validated<T>(pub T);
+
+impl<T: DeserializeOwned + Accord + 'static> FromRequest for Validated<Json<T>> {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let json = Json::<T>::from_request(req, payload);
+        let req = req.clone();
+        Box::pin(async move {
+            let result = json.await.map_err(|e| {
+                let code = match e.as_error::<JsonPayloadError>() {
+                    Some(JsonPayloadError::ContentType) => "accord.body.unsupported_media_type",
+                    Some(JsonPayloadError::Deserialize(e)) if e.is_data() => "accord.body.invalid_data",
+                    Some(JsonPayloadError::Deserialize(_)) => "accord.body.syntax_error",
+                    _ => "accord.body.unreadable",
+                };
+                ValidationError::unreadable(code, &e)
+            });
+            validated(&req, result)
+        })
+    }
+}
+
+impl<T: DeserializeOwned + Accord> FromRequest for Validated<Query<T>> {
+    type Error = Error;
+    type Future = future::Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let result = Query::<T>::from_query(req.query_string())
+            .map_err(|e| ValidationError::unreadable("accord.query.invalid_data", &e.into()));
+        future::ready(validated(req, result))
+    }
+}
+
+impl<T: DeserializeOwned + Accord + 'static> FromRequest for Validated<Form<T>> {
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let form = Form::<T>::from_request(req, payload);
+        let req = req.clone();
+        Box::pin(async move {
+            let result = form.await.map_err(|e| {
+                let code = match e.as_error::<UrlencodedError>() {
+                    Some(UrlencodedError::ContentType) => "accord.body.unsupported_media_type",
+                    Some(UrlencodedError::Parse(_)) => "accord.body.invalid_data",
+                    _ => "accord.body.unreadable",
+                };
+                ValidationError::unreadable(code, &e)
+            });
+            validated(&req, result)
+        })
+    }
+}
+
+/// Validates the extracted value and turns any error into a response by the `ValidationConfig`.
+fn validated<T, U>(req: &HttpRequest, extracted: Result<T, ValidationError>) -> Result<Validated<T>, Error>
+    where T: std::ops::Deref<Target = U>,
+          U: Accord
+{
+    extracted
+        .and_then(|value| {
+            value.validate()?;
+            Ok(Validated(value))
+        })
+        .map_err(|e| match req.app_data::<ValidationConfig>().and_then(|c| c.error_handler.as_ref()) {
+            Some(handler) => handler(e, req),
+            None => e.into(),
+        })
+}
+
+/// Error of the `Validated` extractors, responding with `status` and `error` serialized as JSON.
+#[derive(Debug, Clone)]
+pub struct ValidationError {
+    pub status: StatusCode,
+    pub error: MultipleError,
+}
+
+impl ValidationError {
+    fn unreadable(code: &str, error: &Error) -> ValidationError {
+        ValidationError {
+            status: error.as_response_error().status_code(),
+            error: MultipleError::unreadable(code, error.to_string()),
+        }
+    }
+}
+
+impl From<MultipleError> for ValidationError {
+    fn from(error: MultipleError) -> ValidationError {
+        ValidationError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl ResponseError for ValidationError {
+    fn status_code(&self) -> StatusCode {
+        self.status
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status).json(&self.error)
+    }
+}
+
+/// Configuration of the `Validated` extractors, added as app data.
+#[derive(Clone, Default)]
+pub struct ValidationConfig {
+    error_handler: Option<Arc<dyn Fn(ValidationError, &HttpRequest) -> Error + Send + Sync>>,
+}
+
+impl ValidationConfig {
+    /// Sets the function turning a `ValidationError` into the error responded with.
+    pub fn error_handler<F>(mut self, f: F) -> ValidationConfig
+        where F: Fn(ValidationError, &HttpRequest) -> Error + Send + Sync + 'static
+    {
+        self.error_handler = Some(Arc::new(f));
+        self
+    }
+}
@@ -0,0 +1,139 @@
+use futures::future;
+
+pub use futures::future::BoxFuture;
+
+use crate::{MultipleError, MultipleInvalid, Outcome, Validator};
+
+/// Trait for types whose validation needs to wait for something, like a database lookup.
+pub trait AsyncAccord {
+    fn validate_async(&self) -> BoxFuture<'_, crate::Result>;
+}
+
+/// A rule that data of type `T` can be validated against asynchronously.
+///
+/// Every `Validator` is an `AsyncValidator` that is ready immediately, so synchronous and
+/// asynchronous validators can be mixed in `async_rules!`. Implement it for rules that need I/O:
+///
+/// ```
+/// use accord::{AsyncValidator, BoxFuture, Invalid, ValidatorResult};
+///
+/// struct Available<'a> {
+///     taken: &'a [&'static str],
+/// }
+///
+/// impl<'a> AsyncValidator<String> for Available<'a> {
+///     type Output = ValidatorResult;
+///
+///     fn validate_async<'b>(&'b self, name: &'b String) -> BoxFuture<'b, ValidatorResult> {
+///         Box::pin(async move {
+///             if self.taken.contains(&name.as_str()) {
+///                 Err(Invalid {
+///                     code: "signup.username.taken".to_string(),
+///                     msg: "Must not be taken.".to_string(),
+///                     args: vec![],
+///                     human_readable: "Must not be taken".to_string(),
+///                 })
+///             } else {
+///                 Ok(())
+///             }
+///         })
+///     }
+/// }
+/// ```
+pub trait AsyncValidator<T: ?Sized> {
+    type Output: Outcome;
+
+    fn validate_async<'a>(&'a self, value: &'a T) -> BoxFuture<'a, Self::Output>;
+}
+
+impl<T: ?Sized, V: Validator<T>> AsyncValidator<T> for V
+    where V::Output: Send + 'static
+{
+    type Output = V::Output;
+
+    fn validate_async<'a>(&'a self, value: &'a T) -> BoxFuture<'a, V::Output> {
+        Box::pin(future::ready(self.validate(value)))
+    }
+}
+
+/// Runs the validation of every piece of data in `async_rules!` concurrently.
+#[doc(hidden)]
+pub async fn join_fields(fields: Vec<BoxFuture<'_, Vec<MultipleInvalid>>>) -> crate::Result {
+    let mut invalids = Vec::new();
+    for found in future::join_all(fields).await {
+        let result: crate::Result = Err(MultipleError(found));
+        result.collect("", &mut invalids);
+    }
+    if invalids.is_empty() {
+        Ok(())
+    } else {
+        Err(MultipleError(invalids))
+    }
+}
+
+/// Runs a list of synchronous and asynchronous validators on data.
+///
+/// This is the collection form of `rules!` for `AsyncValidator`s. It evaluates to a future of a
+/// `Result`; the validators of each piece of data run one after another, while the pieces of
+/// data are validated concurrently. Errors are tagged the same way as in `rules!`.
+///
+/// The future takes ownership of the variables used in the rules, so inside `AsyncAccord` refer
+/// to the data through `self`, which is a reference.
+///
+/// ```
+/// #[macro_use]
+/// extern crate accord;
+/// extern crate futures;
+///
+/// use accord::{AsyncAccord, BoxFuture, Result as AccordResult};
+/// use accord::validators::{contains, max};
+///
+/// struct Coupon {
+///     pub code: String,
+/// }
+///
+/// impl AsyncAccord for Coupon {
+///     fn validate_async(&self) -> BoxFuture<'_, AccordResult> {
+///         Box::pin(async_rules!{
+///             "code" => self.code => [max(8), contains("-")]
+///         })
+///     }
+/// }
+///
+/// fn main() {
+///     let coupon = Coupon { code: "SUMMER2024".to_string() };
+///     let result = futures::executor::block_on(coupon.validate_async());
+///
+///     assert_eq!(result.unwrap_err().0[0].invalids.len(), 2);
+/// }
+/// ```
+#[macro_export]
+macro_rules! async_rules {
+    (@collect $fields:ident) => {};
+    (@collect $fields:ident $a:expr => $b:expr => [ $( $c:expr ),* ] $(, $( $rest:tt )* )?) => {
+        $fields.push(::std::boxed::Box::pin(async {
+            let tag = $a.to_string();
+            let mut invalids = Vec::new();
+            $($crate::Outcome::collect(
+                $crate::AsyncValidator::validate_async(&$c, &$b).await, &tag, &mut invalids);)*
+            invalids
+        }));
+        $crate::async_rules!(@collect $fields $($( $rest )*)?);
+    };
+    (@collect $fields:ident $b:expr => [ $( $c:expr ),* ] $(, $( $rest:tt )* )?) => {
+        $fields.push(::std::boxed::Box::pin(async {
+            let mut invalids = Vec::new();
+            $($crate::Outcome::collect(
+                $crate::AsyncValidator::validate_async(&$c, &$b).await, "", &mut invalids);)*
+            invalids
+        }));
+        $crate::async_rules!(@collect $fields $($( $rest )*)?);
+    };
+    ( $( $rules:tt )* ) => {
+        async move {
+            let mut fields: Vec<$crate::BoxFuture<'_, Vec<$crate::MultipleInvalid>>> = Vec::new();
+            $crate::async_rules!(@collect fields $( $rules )*);
+            $crate::join_fields(fields).await
+        }
+    };
+}
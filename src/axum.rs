@@ -0,0 +1,91 @@
+//! Validation of request bodies in [axum](https://docs.rs/axum).
+//!
+//! `Validated<Json<T>>` is an extractor that deserializes a JSON body like `Json<T>` and then
+//! validates it. A body that does not validate is rejected with `422 Unprocessable Entity` and
+//! the serialized `MultipleError` as its body. A body that cannot be deserialized is rejected
+//! with the status `Json` would have used, and its error in the same shape, tagged with an empty
+//! tag.
+//!
+//! ```
+//! # extern crate accord;
+//! # extern crate axum;
+//! use accord::axum::Validated;
+//! use axum::Json;
+//! # use accord::{Accord, Result as AccordResult};
+//! # #[derive(serde_derive::Deserialize)]
+//! # struct Account { name: String }
+//! # impl Accord for Account {
+//! #     fn validate(&self) -> AccordResult { Ok(()) }
+//! # }
+//!
+//! async fn create(Validated(Json(account)): Validated<Json<Account>>) -> String {
+//!     account.name
+//! }
+//! #
+//! # fn main() {
+//! #     let _ = axum::Router::<()>::new().route("/accounts", axum::routing::post(create));
+//! # }
+//! ```
+
+use ::axum::extract::rejection::JsonRejection;
+use ::axum::extract::{FromRequest, Request};
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use ::axum::Json;
+use serde::de::DeserializeOwned;
+
+use crate::{Accord, MultipleError};
+
+/// Extractor running `Accord::validate` on the extracted value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Validated<T>(pub T);
+
+impl<S, T> FromRequest<S> for Validated<Json<T>>
+    where S: Send + Sync,
+          T: DeserializeOwned + Accord
+{
+    type Rejection = ValidationRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state).await?;
+        value.validate()?;
+        Ok(Validated(Json(value)))
+    }
+}
+
+/// Rejection of `Validated`, responding with `status` and `error` serialized as JSON.
+#[derive(Debug, Clone)]
+pub struct ValidationRejection {
+    pub status: StatusCode,
+    pub error: MultipleError,
+}
+
+impl From<MultipleError> for ValidationRejection {
+    fn from(error: MultipleError) -> ValidationRejection {
+        ValidationRejection {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            error,
+        }
+    }
+}
+
+impl From<JsonRejection> for ValidationRejection {
+    fn from(rejection: JsonRejection) -> ValidationRejection {
+        let code = match rejection {
+            JsonRejection::JsonDataError(_) => "accord.body.invalid_data",
+            JsonRejection::JsonSyntaxError(_) => "accord.body.syntax_error",
+            JsonRejection::MissingJsonContentType(_) => "accord.body.unsupported_media_type",
+            _ => "accord.body.unreadable",
+        };
+        ValidationRejection {
+            status: rejection.status(),
+            error: MultipleError::unreadable(code, rejection.body_text()),
+        }
+    }
+}
+
+impl IntoResponse for ValidationRejection {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.error)).into_response()
+    }
+}
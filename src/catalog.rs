@@ -0,0 +1,303 @@
+//! Translation of error messages.
+//!
+//! Translations are keyed by the `code` of an `Invalid`, like `accord.contains.missing`, or by its
+//! `msg`, like *"Must contain %1."*. A `Catalog` maps those keys to translated templates per
+//! locale, loaded from JSON or gettext `.po` files, and renders an `Invalid` by substituting its
+//! `args` into the template. The `msg` itself is the built-in English fallback.
+//!
+//! ```
+//! use accord::Catalog;
+//! use accord::Validator;
+//! use accord::validators::contains;
+//!
+//! let mut catalog = Catalog::new();
+//! catalog.add_json("da", r#"{ "accord.contains.missing": "Skal indeholde %1." }"#).unwrap();
+//!
+//! let invalid = contains("@").validate(&"test".to_string()).unwrap_err();
+//!
+//! assert_eq!(catalog.render(&invalid, "da-DK"), "Skal indeholde @.");
+//! assert_eq!(catalog.render(&invalid, "fr"), "Must contain @.");
+//! ```
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use crate::{Arg, Invalid, MultipleError};
+
+/// Translated message templates per locale.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl Catalog {
+    pub fn new() -> Catalog {
+        Catalog::default()
+    }
+
+    /// Adds a translation of the message `key` for `locale`.
+    pub fn insert(&mut self, locale: &str, key: &str, template: &str) {
+        self.locales
+            .entry(locale.to_string())
+            .or_default()
+            .insert(key.to_string(), template.to_string());
+    }
+
+    /// Adds the translations of a JSON object mapping message keys to templates for `locale`.
+    pub fn add_json(&mut self, locale: &str, json: &str) -> Result<(), CatalogError> {
+        let translations: HashMap<String, String> =
+            serde_json::from_str(json).map_err(CatalogError::Json)?;
+        for (key, template) in translations {
+            self.insert(locale, &key, &template);
+        }
+        Ok(())
+    }
+
+    /// Adds the translations of a gettext `.po` file for `locale`.
+    ///
+    /// Entries without a translation are skipped, as is the header. Only the first form of
+    /// plural entries is used, since messages are not pluralized.
+    pub fn add_po(&mut self, locale: &str, po: &str) -> Result<(), CatalogError> {
+        for (key, template) in parse_po(po)? {
+            self.insert(locale, &key, &template);
+        }
+        Ok(())
+    }
+
+    /// The translated template for the message `key` in `locale`, if there is one.
+    ///
+    /// A locale with a region, like `da-DK` or `da_DK`, falls back to its language, `da`.
+    pub fn template(&self, key: &str, locale: &str) -> Option<&str> {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+        [locale, language]
+            .iter()
+            .filter_map(|locale| self.locales.get(*locale))
+            .filter_map(|translations| translations.get(key))
+            .map(|template| template.as_str())
+            .next()
+    }
+
+    /// The translated template for `invalid` in `locale`, looked up by its `code` first.
+    pub fn translation(&self, invalid: &Invalid, locale: &str) -> Option<&str> {
+        self.template(&invalid.code, locale).or_else(|| self.template(&invalid.msg, locale))
+    }
+
+    /// Renders `invalid` in `locale`, falling back to English.
+    pub fn render(&self, invalid: &Invalid, locale: &str) -> String {
+        let template = self.translation(invalid, locale).unwrap_or(&invalid.msg);
+        substitute(template, &invalid.args)
+    }
+
+    /// Replaces the `human_readable` of every error in `error` with its translation in `locale`.
+    ///
+    /// Errors without a translation keep their English `human_readable`.
+    pub fn localize(&self, error: &MultipleError, locale: &str) -> MultipleError {
+        let mut error = error.clone();
+        for invalid in error.0.iter_mut().flat_map(|m| m.invalids.iter_mut()) {
+            if let Some(template) = self.translation(invalid, locale) {
+                invalid.human_readable = substitute(template, &invalid.args);
+            }
+        }
+        error
+    }
+}
+
+/// Replaces the numbered placeholders `%1`, `%2`, ... in `template` with `args`.
+///
+/// Placeholders without a matching argument are left as they are.
+pub fn substitute(template: &str, args: &[Arg]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(i) = rest.find('%') {
+        rendered.push_str(&rest[..i]);
+        let digits = rest[i + 1..].chars().take_while(|c| c.is_ascii_digit()).count();
+        let arg = rest[i + 1..i + 1 + digits]
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|n| args.get(n));
+        match arg {
+            Some(arg) => rendered.push_str(&arg.to_string()),
+            None => rendered.push_str(&rest[i..i + 1 + digits]),
+        }
+        rest = &rest[i + 1 + digits..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Error returned when translations cannot be loaded.
+#[derive(Debug)]
+pub enum CatalogError {
+    Json(serde_json::Error),
+    Po { line: usize, msg: String },
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CatalogError::Json(ref e) => write!(f, "invalid JSON translations: {}", e),
+            CatalogError::Po { line, ref msg } => write!(f, "invalid .po file at line {}: {}", line, msg),
+        }
+    }
+}
+
+impl error::Error for CatalogError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            CatalogError::Json(ref e) => Some(e),
+            CatalogError::Po { .. } => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum PoField {
+    None,
+    Context,
+    Id,
+    Plural,
+    Str,
+    OtherStr,
+}
+
+/// Parses the translated `msgid`/`msgstr` pairs of a `.po` file.
+fn parse_po(po: &str) -> Result<Vec<(String, String)>, CatalogError> {
+    let mut entries = Vec::new();
+    let mut field = PoField::None;
+    let mut id = String::new();
+    let mut text = String::new();
+
+    fn finish(entries: &mut Vec<(String, String)>, id: &mut String, text: &mut String) {
+        if !id.is_empty() && !text.is_empty() {
+            entries.push((id.clone(), text.clone()));
+        }
+        id.clear();
+        text.clear();
+    }
+
+    for (n, line) in po.lines().enumerate() {
+        let line = line.trim();
+        let error = |msg: &str| CatalogError::Po { line: n + 1, msg: msg.to_string() };
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('"') {
+            let s = unquote(line).ok_or_else(|| error("unterminated string"))?;
+            match field {
+                PoField::Id => id.push_str(&s),
+                PoField::Str => text.push_str(&s),
+                PoField::None => return Err(error("string outside of an entry")),
+                _ => {}
+            }
+            continue;
+        }
+        let (keyword, rest) = line.split_at(line.find(' ').ok_or_else(|| error("expected a string"))?);
+        let s = unquote(rest.trim()).ok_or_else(|| error("unterminated string"))?;
+        field = match keyword {
+            "msgctxt" => PoField::Context,
+            "msgid" => PoField::Id,
+            "msgid_plural" => PoField::Plural,
+            "msgstr" | "msgstr[0]" => PoField::Str,
+            k if k.starts_with("msgstr[") => PoField::OtherStr,
+            _ => return Err(error("unknown keyword")),
+        };
+        match field {
+            PoField::Context => finish(&mut entries, &mut id, &mut text),
+            PoField::Id => {
+                if !text.is_empty() {
+                    finish(&mut entries, &mut id, &mut text);
+                }
+                id = s;
+            }
+            PoField::Str => text = s,
+            _ => {}
+        }
+    }
+    finish(&mut entries, &mut id, &mut text);
+    Ok(entries)
+}
+
+/// Returns the contents of a quoted `.po` string with its escapes resolved.
+fn unquote(s: &str) -> Option<String> {
+    if s.len() < 2 || !s.starts_with('"') || !s.ends_with('"') {
+        return None;
+    }
+    let mut unquoted = String::new();
+    let mut chars = s[1..s.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unquoted.push('\n'),
+            Some('t') => unquoted.push('\t'),
+            Some(c) => unquoted.push(c),
+            None => return None,
+        }
+    }
+    Some(unquoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<Arg> {
+        args.iter().map(Arg::from).collect()
+    }
+
+    // substitute
+    #[test]
+    pub fn substitute_args() {
+        assert_eq!(substitute("Between %1 and %2.", &args(&["1", "64"])), "Between 1 and 64.");
+        assert_eq!(substitute("%2 before %1", &args(&["a", "b"])), "b before a");
+        assert_eq!(substitute("%10%", &args(&["a"])), "%10%");
+        assert_eq!(substitute("100% sure", &args(&[])), "100% sure");
+        assert_eq!(substitute("Between %1 and %2.", &[Arg::named("min", &1), Arg::named("max", &64)]),
+                   "Between 1 and 64.");
+        assert_eq!(substitute("One of %1.", &[Arg::list(&["a", "b"])]), "One of a, b.");
+    }
+
+    // parse_po
+    #[test]
+    pub fn parse_po_valid() {
+        let po = r#"
+# Danish translations
+msgid ""
+msgstr ""
+"Language: da\n"
+
+#: src/validators/contains.rs
+msgid "Must contain %1."
+msgstr "Skal indeholde %1."
+
+msgid ""
+"Must not contain "
+"%1."
+msgstr "Må ikke indeholde \"%1\"."
+
+msgid "Untranslated"
+msgstr ""
+
+msgctxt "unused"
+msgid "%1 item"
+msgid_plural "%1 items"
+msgstr[0] "%1 element"
+msgstr[1] "%1 elementer"
+"#;
+        assert_eq!(parse_po(po).unwrap(),
+                   vec![("Must contain %1.".to_string(), "Skal indeholde %1.".to_string()),
+                        ("Must not contain %1.".to_string(), "Må ikke indeholde \"%1\".".to_string()),
+                        ("%1 item".to_string(), "%1 element".to_string())]);
+    }
+
+    #[test]
+    pub fn parse_po_invalid() {
+        assert!(parse_po("msgid \"Must contain %1.\nmsgstr \"\"").is_err());
+        assert!(parse_po("msgfoo \"bar\"").is_err());
+        assert!(parse_po("\"dangling\"").is_err());
+    }
+}
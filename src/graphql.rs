@@ -0,0 +1,65 @@
+//! GraphQL errors for validation errors.
+//!
+//! Every `Invalid` of a `MultipleError` becomes an entry of the `errors` array of a GraphQL
+//! response. The `message` is the `human_readable` of the `Invalid`, while the rest of it, and
+//! the path to the invalid input given by its tag, goes in the `extensions`.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate accord;
+//!
+//! use accord::graphql;
+//! use accord::validators::contains;
+//!
+//! fn main() {
+//!     let email = "test".to_string();
+//!     let error = rules!{ "email" => email => [contains("@")] }.unwrap_err();
+//!
+//!     let errors = graphql::errors(&error);
+//!
+//!     assert_eq!(errors[0].message, "Must contain '@'");
+//!     assert_eq!(errors[0].extensions.field, "email");
+//! }
+//! ```
+
+use crate::{Arg, MultipleError, PathSegment};
+
+/// A GraphQL error.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphQLError {
+    pub message: String,
+    pub extensions: Extensions,
+}
+
+/// The `extensions` of a `GraphQLError`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Extensions {
+    /// The `code` of the `Invalid`.
+    pub code: String,
+    /// The tag of the invalid input, like `address.zip`.
+    pub field: String,
+    /// The segments of the tag, like `["address", "zip"]`.
+    pub path: Vec<PathSegment>,
+    pub msg: String,
+    pub args: Vec<Arg>,
+}
+
+/// The errors of `error`, one for each `Invalid`.
+pub fn errors(error: &MultipleError) -> Vec<GraphQLError> {
+    error.0
+        .iter()
+        .flat_map(|m| {
+            let path = m.path();
+            m.invalids.iter().map(move |invalid| GraphQLError {
+                message: invalid.human_readable.clone(),
+                extensions: Extensions {
+                    code: invalid.code.clone(),
+                    field: m.tag.clone(),
+                    path: path.clone(),
+                    msg: invalid.msg.clone(),
+                    args: invalid.args.clone(),
+                },
+            })
+        })
+        .collect()
+}
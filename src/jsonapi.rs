@@ -0,0 +1,101 @@
+//! JSON:API error objects for validation errors.
+//!
+//! Every `Invalid` of a `MultipleError` becomes an entry of the top-level `errors` array of a
+//! JSON:API document, with a `source.pointer` to the attribute given by its tag, e.g. the tag
+//! `address.zip` points to `/data/attributes/address/zip`.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate accord;
+//!
+//! use accord::jsonapi::Document;
+//! use accord::validators::contains;
+//!
+//! fn main() {
+//!     let email = "test".to_string();
+//!     let error = rules!{ "email" => email => [contains("@")] }.unwrap_err();
+//!
+//!     let document = Document::from(&error);
+//!
+//!     assert_eq!(document.errors[0].status, "422");
+//!     assert_eq!(document.errors[0].source.pointer, "/data/attributes/email");
+//! }
+//! ```
+
+use crate::{Arg, MultipleError};
+
+/// The pointer prefix used for tags by `Document::from`.
+pub const ATTRIBUTES: &str = "/data/attributes";
+
+/// A JSON:API document holding only errors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    pub errors: Vec<ErrorObject>,
+}
+
+/// A JSON:API error object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorObject {
+    pub status: String,
+    /// The `code` of the `Invalid`.
+    pub code: String,
+    /// The `msg` of the `Invalid`, which is the same for every occurrence of the error.
+    pub title: String,
+    /// The `human_readable` of the `Invalid`.
+    pub detail: String,
+    pub source: Source,
+    pub meta: Meta,
+}
+
+/// The data an `ErrorObject` is about.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Source {
+    /// JSON pointer to the invalid data.
+    pub pointer: String,
+}
+
+/// Non-standard members of an `ErrorObject`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Meta {
+    /// The `args` of the `Invalid`, for rendering the `title` in another language.
+    pub args: Vec<Arg>,
+}
+
+impl Document {
+    /// The errors of `error`, with tags pointing into the data below `prefix`, e.g. `/data` for
+    /// errors tagged like `attributes.email`.
+    pub fn with_prefix(error: &MultipleError, prefix: &str) -> Document {
+        let errors = error.0
+            .iter()
+            .flat_map(|m| {
+                let pointer = m.path()
+                    .iter()
+                    .fold(prefix.to_string(), |pointer, segment| {
+                        let segment = segment.to_string().replace('~', "~0").replace('/', "~1");
+                        format!("{}/{}", pointer, segment)
+                    });
+                m.invalids.iter().map(move |invalid| ErrorObject {
+                    status: "422".to_string(),
+                    code: invalid.code.clone(),
+                    title: invalid.msg.clone(),
+                    detail: invalid.human_readable.clone(),
+                    source: Source { pointer: pointer.clone() },
+                    meta: Meta { args: invalid.args.clone() },
+                })
+            })
+            .collect();
+        Document { errors }
+    }
+}
+
+impl<'a> From<&'a MultipleError> for Document {
+    fn from(error: &'a MultipleError) -> Document {
+        Document::with_prefix(error, ATTRIBUTES)
+    }
+}
+
+impl From<MultipleError> for Document {
+    fn from(error: MultipleError) -> Document {
+        Document::from(&error)
+    }
+}
@@ -1,15 +1,40 @@
-extern crate serde;
+#![allow(clippy::type_complexity)]
+
+extern crate futures;
 extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
 
 #[cfg(feature = "regex_validator")]
 extern crate regex;
+#[cfg(feature = "serde_path_to_error")]
+extern crate serde_path_to_error;
 
 pub mod validators;
 pub mod serde_types;
+pub mod serde;
+pub mod catalog;
+pub mod problem;
+#[cfg(feature = "jsonapi")]
+pub mod jsonapi;
+#[cfg(feature = "graphql")]
+pub mod graphql;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+mod async_validator;
+mod settings;
+mod validator;
 
-pub use serde_types::*;
+pub use crate::serde_types::*;
+pub use crate::catalog::{Catalog, CatalogError};
+pub use crate::problem::{InvalidParam, ProblemDetails};
+pub use crate::async_validator::{join_fields, AsyncAccord, AsyncValidator, BoxFuture};
+pub use crate::settings::{Collector, FailFast, Settings};
+pub use crate::validator::{Validator, ValidatorExt, Validators};
 
 pub type ValidatorResult = std::result::Result<(), Invalid>;
 
@@ -18,6 +43,97 @@ pub type Result = std::result::Result<(), MultipleError>;
 /// Trait that can be used for accepting types that can be validated
 pub trait Accord {
     fn validate(&self) -> Result;
+
+    /// Validates according to `settings`, e.g. stopping at the first error.
+    ///
+    /// By default this runs `validate` and drops the errors `settings` would not have collected,
+    /// so it does no early stopping: every validator still runs. Implement it with the settings
+    /// form of `rules!` to skip running those validators instead, as `#[derive(Accord)]` does.
+    /// The settings are then passed on to `validators::nested` and `validators::each`, so nested
+    /// data stops early as well.
+    fn validate_with_settings(&self, settings: &Settings) -> Result {
+        settings.apply(self.validate())
+    }
+}
+
+/// Trait for types that are validated against a context, like the configuration of a tenant.
+///
+/// The context can be used by every validator given to `rules!`, e.g.
+/// `"quantity" => self.quantity => [range(1, ctx.stock(&self.sku))]`, and passed on to data that
+/// implements `AccordWith` itself with `validators::nested_with`.
+pub trait AccordWith<C: ?Sized> {
+    fn validate_with(&self, ctx: &C) -> Result;
+}
+
+/// The result of running a `Validator`.
+///
+/// Validators usually return a `ValidatorResult`, whose `Invalid` is tagged with the tag of the
+/// data. Validators returning a `Result`, like `validators::nested`, have the tags of their errors
+/// joined onto the tag of the data instead, so an error tagged `street` becomes `address.street`.
+/// Validators returning a `Vec` of outcomes have each of them collected, and validators returning
+/// an `Option`, like `validators::optional`, only if it is `Some`.
+///
+/// The single form of `rules!` has no tags, so there only the `Invalid`s are kept.
+pub trait Outcome {
+    /// Adds the errors of this outcome to `invalids` under `tag`.
+    fn collect(self, tag: &str, invalids: &mut Vec<MultipleInvalid>);
+}
+
+impl Outcome for ValidatorResult {
+    fn collect(self, tag: &str, invalids: &mut Vec<MultipleInvalid>) {
+        if let Err(invalid) = self {
+            push_invalids(invalids, tag, vec![invalid]);
+        }
+    }
+}
+
+impl<O: Outcome> Outcome for Vec<O> {
+    fn collect(self, tag: &str, invalids: &mut Vec<MultipleInvalid>) {
+        for outcome in self {
+            outcome.collect(tag, invalids);
+        }
+    }
+}
+
+impl<O: Outcome> Outcome for Option<O> {
+    fn collect(self, tag: &str, invalids: &mut Vec<MultipleInvalid>) {
+        if let Some(outcome) = self {
+            outcome.collect(tag, invalids);
+        }
+    }
+}
+
+impl Outcome for Result {
+    fn collect(self, tag: &str, invalids: &mut Vec<MultipleInvalid>) {
+        if let Err(MultipleError(multiple_invalids)) = self {
+            for m in multiple_invalids {
+                push_invalids(invalids, &join_tags(tag, &m.tag), m.invalids);
+            }
+        }
+    }
+}
+
+/// Joins the tag of a value with the tag of an error found inside it.
+///
+/// Tags are joined with a `.`, unless either is empty or `child` is an index like `[3]`.
+pub fn join_tags(parent: &str, child: &str) -> String {
+    if parent.is_empty() || child.is_empty() || child.starts_with('[') {
+        format!("{}{}", parent, child)
+    } else {
+        format!("{}.{}", parent, child)
+    }
+}
+
+/// Adds `new` to the `MultipleInvalid` tagged `tag`, creating it if needed.
+fn push_invalids(invalids: &mut Vec<MultipleInvalid>, tag: &str, new: Vec<Invalid>) {
+    if let Some(m) = invalids.iter_mut().find(|m| m.tag == tag) {
+        m.invalids.extend(new);
+        return;
+    }
+    invalids.push(MultipleInvalid {
+        tag: tag.to_string(),
+        invalids: new,
+    });
 }
 
 /// Runs a list of validators on data.
@@ -100,38 +216,123 @@ pub trait Accord {
 ///     };
 /// }
 /// ```
+///
+/// In the collection form, validators may also return a `Result` instead of a `ValidatorResult`,
+/// like `validators::nested` which validates data that implements `Accord` itself. The tags of
+/// those errors are joined onto the tag of the data, so `"address" => self.address => [nested()]`
+/// reports errors tagged `address.street`, `address.zip` and so on.
+///
+/// Rules that depend on more than one piece of data can be given without a tag. Their validators
+/// receive the whole data and tag the errors themselves, typically by using `rules!` again:
+///
+/// ```
+/// #[macro_use]
+/// extern crate accord;
+///
+/// use accord::{Accord, Result as AccordResult};
+/// use accord::validators::{eq, length};
+///
+/// struct Signup {
+///     pub password: String,
+///     pub password_confirmation: String,
+/// }
+///
+/// fn passwords_match(signup: &Signup) -> AccordResult {
+///     rules!{
+///         "password_confirmation" => signup.password_confirmation => [eq(signup.password.clone())]
+///     }
+/// }
+///
+/// impl Accord for Signup {
+///     #[cfg(not(feature = "inclusive_range"))]
+///     fn validate(&self) -> AccordResult {
+///         rules!{
+///             "password" => self.password => [length(8, 64)],
+///             self => [passwords_match]
+///         }
+///     }
+///
+///     #[cfg(feature = "inclusive_range")]
+///     fn validate(&self) -> AccordResult {
+///         rules!{
+///             "password" => self.password => [length(8..=64)],
+///             self => [passwords_match]
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let signup = Signup {
+///         password: "kfjsdkfjsdkfjfksjdfkdsfjs".to_string(),
+///         password_confirmation: "kfjsdkfjsdkfjfksjdfkdsfj".to_string(),
+///     };
+///
+///     assert_eq!(signup.validate().unwrap_err().0[0].tag, "password_confirmation");
+/// }
+/// ```
+///
+/// The collection form can be given `Settings` in front of the rules, separated by a `;`, to stop
+/// running validators early or to limit the number of errors collected:
+///
+/// ```
+/// #[macro_use]
+/// extern crate accord;
+///
+/// use accord::{FailFast, Settings};
+/// use accord::validators::{contains, max};
+///
+/// fn main() {
+///     let email = "test".to_string();
+///     let settings = Settings { fail_fast: FailFast::PerField, max_errors: None };
+///
+///     let result = rules!{settings;
+///         "email" => email => [contains("@"), contains("."), max(3)]
+///     };
+///
+///     assert_eq!(result.unwrap_err().0[0].invalids.len(), 1);
+/// }
+/// ```
 #[macro_export]
 macro_rules! rules {
+    (@collect $collector:ident) => {};
+    (@collect $collector:ident $a:expr => $b:expr => [ $( $c:expr ),* ] $(, $( $rest:tt )* )?) => {
+        {
+            let tag = $a.to_string();
+            $collector.begin_field();
+            $(if $collector.proceed() {
+                let settings = $collector.settings();
+                $collector.collect($crate::Validator::validate_with_settings(&$c, &$b, settings), &tag);
+            })*
+        }
+        $crate::rules!(@collect $collector $($( $rest )*)?);
+    };
+    (@collect $collector:ident $b:expr => [ $( $c:expr ),* ] $(, $( $rest:tt )* )?) => {
+        $collector.begin_field();
+        $(if $collector.proceed() {
+            let settings = $collector.settings();
+            $collector.collect($crate::Validator::validate_with_settings(&$c, &$b, settings), "");
+        })*
+        $crate::rules!(@collect $collector $($( $rest )*)?);
+    };
     ( $a:expr, [ $( $b:expr ),* ] ) => {{
-        let invalids = [$($b(&$a)),*]
-           .iter()
-           .cloned()
-           .filter_map(move |r| r.err())
-           .collect::<Vec<_>>();
-        if invalids.len() > 0 {
+        let mut multiple_invalids = Vec::new();
+        $($crate::Outcome::collect($crate::Validator::validate(&$b, &$a), "", &mut multiple_invalids);)*
+        let invalids = multiple_invalids.into_iter()
+            .flat_map(|m| m.invalids)
+            .collect::<Vec<_>>();
+        if !invalids.is_empty() {
             Err(Error(invalids))
         } else {
             Ok(())
         }
     }};
-    ( $( $a:expr => $b:expr => [ $( $c:expr ),* ] ),* ) => {{
-        use accord::{MultipleInvalid, MultipleError};
-		let multiple_invalids = vec![$(MultipleInvalid {
-                tag: $a.to_string(),
-                invalids: [$($c(&$b)),*]
-                    .iter()
-                    .cloned()
-                    .filter_map(move |r| r.err())
-                    .collect::<Vec<_>>()
-            }),*]
-            .iter()
-            .cloned()
-            .filter(|m| m.invalids.len() > 0)
-            .collect::<Vec<_>>();
-        if multiple_invalids.len() > 0 {
-            Err(MultipleError(multiple_invalids))
-        } else {
-            Ok(())
-        }
+    ( $settings:expr ; $( $rules:tt )* ) => {{
+        let settings: &$crate::Settings = &$settings;
+        let mut collector = $crate::Collector::new(settings);
+        $crate::rules!(@collect collector $( $rules )*);
+        collector.finish()
     }};
+    ( $( $rules:tt )* ) => {
+        $crate::rules!($crate::Settings::default(); $( $rules )*)
+    };
 }
@@ -0,0 +1,99 @@
+//! RFC 7807 Problem Details for validation errors.
+//!
+//! A `MultipleError` converts into a `ProblemDetails` document, which lists every `Invalid` as an
+//! entry of the `invalid-params` extension with the tag as its `name` and the `human_readable` as
+//! its `reason`. Serialized with Serde, it is the body of an `application/problem+json` response.
+//!
+//! ```
+//! #[macro_use]
+//! extern crate accord;
+//!
+//! use accord::ProblemDetails;
+//! use accord::validators::contains;
+//!
+//! fn main() {
+//!     let email = "test".to_string();
+//!     let error = rules!{ "email" => email => [contains("@")] }.unwrap_err();
+//!
+//!     let problem = ProblemDetails::from(error);
+//!
+//!     assert_eq!(problem.status, 422);
+//!     assert_eq!(problem.invalid_params[0].name, "email");
+//!     assert_eq!(problem.invalid_params[0].reason, "Must contain '@'");
+//! }
+//! ```
+
+use crate::MultipleError;
+
+/// The media type of a serialized `ProblemDetails`.
+pub const CONTENT_TYPE: &str = "application/problem+json";
+
+/// An RFC 7807 problem details document describing why a request did not validate.
+///
+/// The fields can be changed before serializing, e.g. to point `type` to the documentation of
+/// the error contract of a service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProblemDetails {
+    /// URI identifying the problem type, `about:blank` by default.
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(rename = "invalid-params", default)]
+    pub invalid_params: Vec<InvalidParam>,
+}
+
+/// An entry of the `invalid-params` extension of `ProblemDetails`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvalidParam {
+    /// The tag of the invalid data, like `email` or `address.zip`.
+    pub name: String,
+    pub reason: String,
+    /// The `code` of the `Invalid`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub code: String,
+}
+
+impl<'a> From<&'a MultipleError> for ProblemDetails {
+    fn from(error: &'a MultipleError) -> ProblemDetails {
+        let invalid_params = error.0
+            .iter()
+            .flat_map(|m| {
+                m.invalids.iter().map(move |invalid| InvalidParam {
+                    name: m.tag.clone(),
+                    reason: invalid.human_readable.clone(),
+                    code: invalid.code.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let detail = match invalid_params.len() {
+            1 => "1 parameter is invalid.".to_string(),
+            n => format!("{} parameters are invalid.", n),
+        };
+        ProblemDetails {
+            problem_type: "about:blank".to_string(),
+            title: "Unprocessable Entity".to_string(),
+            status: 422,
+            detail: Some(detail),
+            instance: None,
+            invalid_params,
+        }
+    }
+}
+
+impl From<MultipleError> for ProblemDetails {
+    fn from(error: MultipleError) -> ProblemDetails {
+        ProblemDetails::from(&error)
+    }
+}
+
+impl MultipleError {
+    /// The errors as an RFC 7807 problem details document.
+    pub fn to_problem_details(&self) -> ProblemDetails {
+        ProblemDetails::from(self)
+    }
+}
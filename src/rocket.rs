@@ -0,0 +1,102 @@
+//! Validation of request bodies in [Rocket](https://rocket.rs).
+//!
+//! `Validated<Json<T>>` is a data guard that deserializes a JSON body like `Json<T>` and then
+//! validates it. A body that does not validate fails with `422 Unprocessable Entity` and a
+//! `ValidationError` holding the `MultipleError`. A body that cannot be deserialized fails with
+//! the status `Json` would have used, and its error in the same shape, tagged with an empty tag.
+//!
+//! Take the guard as a `Result` to respond with the error, serialized as JSON:
+//!
+//! ```
+//! # extern crate accord;
+//! # #[macro_use] extern crate rocket;
+//! use accord::rocket::{Validated, ValidationError};
+//! use rocket::serde::json::Json;
+//! # use accord::{Accord, Result as AccordResult};
+//! # #[derive(serde_derive::Deserialize)]
+//! # struct Account { name: String }
+//! # impl Accord for Account {
+//! #     fn validate(&self) -> AccordResult { Ok(()) }
+//! # }
+//!
+//! #[post("/accounts", data = "<account>")]
+//! fn create(account: Result<Validated<Json<Account>>, ValidationError>)
+//!           -> Result<String, ValidationError> {
+//!     let Validated(Json(account)) = account?;
+//!     Ok(account.name)
+//! }
+//! #
+//! # fn main() {
+//! #     let _ = rocket::build().mount("/", routes![create]);
+//! # }
+//! ```
+
+use ::rocket::data::{self, Data, FromData};
+use ::rocket::http::Status;
+use ::rocket::outcome::Outcome;
+use ::rocket::request::Request;
+use ::rocket::response::{self, Responder};
+use ::rocket::serde::json::{self, Json};
+use serde::Deserialize;
+
+use crate::{Accord, MultipleError};
+
+/// Data guard running `Accord::validate` on the guarded value.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Validated<T>(pub T);
+
+#[::rocket::async_trait]
+impl<'r, T: Deserialize<'r> + Accord> FromData<'r> for Validated<Json<T>> {
+    type Error = ValidationError;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        match Json::<T>::from_data(req, data).await {
+            Outcome::Success(json) => match json.validate() {
+                Ok(()) => Outcome::Success(Validated(json)),
+                Err(error) => Outcome::Error((Status::UnprocessableEntity, error.into())),
+            },
+            Outcome::Error((status, e)) => {
+                let code = match e {
+                    json::Error::Parse(_, ref e) if e.is_data() => "accord.body.invalid_data",
+                    json::Error::Parse(..) => "accord.body.syntax_error",
+                    json::Error::Io(_) => "accord.body.unreadable",
+                };
+                let error = ValidationError {
+                    status,
+                    error: MultipleError::unreadable(code, e.to_string()),
+                };
+                Outcome::Error((status, error))
+            }
+            Outcome::Forward(f) => Outcome::Forward(f),
+        }
+    }
+}
+
+/// Error of the `Validated` data guard, responding with `status` and `error` serialized as JSON.
+#[derive(Debug, Clone)]
+pub struct ValidationError {
+    pub status: Status,
+    pub error: MultipleError,
+}
+
+impl From<MultipleError> for ValidationError {
+    fn from(error: MultipleError) -> ValidationError {
+        ValidationError {
+            status: Status::UnprocessableEntity,
+            error,
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for ValidationError {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        (self.status, Json(self.error)).respond_to(req)
+    }
+}
+
+/// Responds with `422 Unprocessable Entity` and the errors serialized as JSON.
+impl<'r> Responder<'r, 'static> for MultipleError {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        ValidationError::from(self).respond_to(req)
+    }
+}
@@ -0,0 +1,385 @@
+//! Validation while deserializing with Serde.
+//!
+//! `Validated<T>` deserializes a `T` and then validates it, so a `Validated<T>` is always valid.
+//! Errors are turned into a Serde error holding the `Display` of the `MultipleError`, e.g. for a
+//! config file:
+//!
+//! ```
+//! # #[macro_use] extern crate accord;
+//! # extern crate serde_json;
+//! use accord::serde::Validated;
+//! use accord::validators::range;
+//! # use accord::{Accord, Result as AccordResult};
+//!
+//! #[derive(Debug, serde_derive::Deserialize)]
+//! struct Config {
+//!     workers: u32,
+//! }
+//!
+//! #[cfg(not(feature = "inclusive_range"))]
+//! impl Accord for Config {
+//!     fn validate(&self) -> AccordResult {
+//!         rules!{ "workers" => self.workers => [range(1, 64)] }
+//!     }
+//! }
+//!
+//! #[cfg(feature = "inclusive_range")]
+//! impl Accord for Config {
+//!     fn validate(&self) -> AccordResult {
+//!         rules!{ "workers" => self.workers => [range(1..=64)] }
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let config: Validated<Config> = serde_json::from_str(r#"{ "workers": 4 }"#).unwrap();
+//! assert_eq!(config.workers, 4);
+//!
+//! let error = serde_json::from_str::<Validated<Config>>(r#"{ "workers": 0 }"#).unwrap_err();
+//! assert!(error.to_string().starts_with("workers: Must be between 1 and 64"));
+//! # }
+//! ```
+//!
+//! Use `Validated::deserialize_validated` instead to get the `MultipleError` itself.
+//!
+//! Single fields are validated while they are deserialized with a `Rule`, defined by `rule!`,
+//! either by wrapping them in `Checked<T, R>` or with `#[serde(deserialize_with = "...")]` and
+//! `check`:
+//!
+//! ```
+//! # #[macro_use] extern crate accord;
+//! # extern crate serde_json;
+//! use accord::serde::Checked;
+//! use accord::validators::{alphanumeric, max};
+//!
+//! rule!(Username: String => [max(16), alphanumeric()]);
+//!
+//! #[derive(Debug, serde_derive::Deserialize)]
+//! struct Signup {
+//!     username: Checked<String, Username>,
+//!     #[serde(deserialize_with = "accord::serde::check::<Username, _, _>")]
+//!     nickname: String,
+//! }
+//!
+//! # fn main() {
+//! let json = r#"{ "username": "test", "nickname": "not valid!" }"#;
+//! let error = serde_json::from_str::<Signup>(json).unwrap_err();
+//! assert!(error.to_string().starts_with("Must not contain ' '"));
+//! # }
+//! ```
+//!
+//! With the `serde_path_to_error` feature, deserializing with `deserialize` rather than the
+//! format's own function gives the errors as a `MultipleError` tagged with the path to the
+//! offending field.
+
+#[cfg(feature = "serde_path_to_error")]
+use std::cell::RefCell;
+use std::error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use ::serde::de::{Deserialize, Deserializer, Error as DeError};
+use ::serde::ser::{Serialize, Serializer};
+#[cfg(feature = "serde_path_to_error")]
+use serde_path_to_error::Segment;
+
+use crate::{Accord, Error, MultipleError};
+#[cfg(feature = "serde_path_to_error")]
+use crate::{join_tags, MultipleInvalid};
+
+#[cfg(feature = "serde_path_to_error")]
+thread_local! {
+    /// While `deserialize` runs, the error of the last `Validated` or `Checked`, if it failed.
+    /// `None` when `deserialize` is not running, so nothing is kept.
+    static FAILED: RefCell<Option<Option<Failed>>> = const { RefCell::new(None) };
+}
+
+/// The errors of a `Validated` or `Checked` that failed, and the message of its Serde error.
+#[cfg(feature = "serde_path_to_error")]
+struct Failed {
+    msg: String,
+    invalids: Vec<MultipleInvalid>,
+}
+
+/// Keeps `failed` as the last error if `deserialize` is running.
+#[cfg(feature = "serde_path_to_error")]
+fn keep(failed: Option<Failed>) {
+    FAILED.with(|last| {
+        if let Some(ref mut last) = *last.borrow_mut() {
+            *last = failed;
+        }
+    });
+}
+
+/// Makes the Serde error for `error`, keeping it for `deserialize`.
+fn fail<E: DeError>(error: MultipleError) -> E {
+    let msg = error.to_string();
+    let serde_error = E::custom(&msg);
+    #[cfg(feature = "serde_path_to_error")]
+    keep(Some(Failed { msg, invalids: error.0 }));
+    serde_error
+}
+
+/// Forgets the error kept by `fail` once a `Validated` or `Checked` has deserialized.
+fn pass<T>(value: T) -> T {
+    #[cfg(feature = "serde_path_to_error")]
+    keep(None);
+    value
+}
+
+/// A value that has been validated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Validated<T>(T);
+
+impl<T: Accord> Validated<T> {
+    /// Validates `value`.
+    pub fn new(value: T) -> Result<Validated<T>, MultipleError> {
+        value.validate()?;
+        Ok(Validated(value))
+    }
+
+    /// Deserializes and validates a `T`, keeping the `MultipleError` if it does not validate.
+    pub fn deserialize_validated<'de, D>(deserializer: D)
+                                         -> Result<Validated<T>, DeserializeError<D::Error>>
+        where D: Deserializer<'de>,
+              T: Deserialize<'de>
+    {
+        let value = T::deserialize(deserializer).map_err(DeserializeError::Deserialize)?;
+        Validated::new(value).map_err(DeserializeError::Invalid)
+    }
+}
+
+impl<T> Validated<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Validated<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Accord> Deserialize<'de> for Validated<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Validated<T>, D::Error> {
+        Validated::deserialize_validated(deserializer).map(pass).map_err(|e| match e {
+            DeserializeError::Deserialize(e) => e,
+            DeserializeError::Invalid(e) => fail(e),
+        })
+    }
+}
+
+/// Serialized as the `T` itself.
+impl<T: Serialize> Serialize for Validated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Error returned by `Validated::deserialize_validated`.
+#[derive(Debug)]
+pub enum DeserializeError<E> {
+    /// The data could not be deserialized.
+    Deserialize(E),
+    /// The data was deserialized, but did not validate.
+    Invalid(MultipleError),
+}
+
+impl<E: fmt::Display> fmt::Display for DeserializeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DeserializeError::Deserialize(ref e) => write!(f, "{}", e),
+            DeserializeError::Invalid(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for DeserializeError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            DeserializeError::Deserialize(ref e) => Some(e),
+            DeserializeError::Invalid(ref e) => Some(e),
+        }
+    }
+}
+
+/// Validation rules for a single value, used by `Checked` and `check`.
+///
+/// Rules are usually defined with `rule!`.
+pub trait Rule<T: ?Sized> {
+    fn check(value: &T) -> Result<(), Error>;
+}
+
+/// Defines a `Rule` named `$name` for values of type `$t`, running the listed validators.
+///
+/// ```
+/// # #[macro_use] extern crate accord;
+/// use accord::validators::{alphanumeric, max};
+///
+/// rule!(pub Username: String => [max(16), alphanumeric()]);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! rule {
+    ( $( #[$meta:meta] )* $vis:vis $name:ident : $t:ty => [ $( $v:expr ),* ] ) => {
+        $( #[$meta] )*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        $vis struct $name;
+
+        impl $crate::serde::Rule<$t> for $name {
+            fn check(value: &$t) -> ::std::result::Result<(), $crate::Error> {
+                let mut multiple_invalids = Vec::new();
+                $($crate::Outcome::collect($crate::Validator::validate(&$v, value), "",
+                                           &mut multiple_invalids);)*
+                let invalids = multiple_invalids.into_iter()
+                    .flat_map(|m| m.invalids)
+                    .collect::<Vec<_>>();
+                if invalids.is_empty() {
+                    Ok(())
+                } else {
+                    Err($crate::Error(invalids))
+                }
+            }
+        }
+    };
+}
+
+/// A field checked by the rule `R` while it is deserialized.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checked<T, R> {
+    value: T,
+    rule: PhantomData<fn() -> R>,
+}
+
+impl<T, R: Rule<T>> Checked<T, R> {
+    /// Checks `value`.
+    pub fn new(value: T) -> Result<Checked<T, R>, Error> {
+        R::check(&value)?;
+        Ok(Checked { value, rule: PhantomData })
+    }
+}
+
+impl<T, R> Checked<T, R> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, R> Deref for Checked<T, R> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, R> AsRef<T> for Checked<T, R> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<'de, T: Deserialize<'de>, R: Rule<T>> Deserialize<'de> for Checked<T, R> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Checked<T, R>, D::Error> {
+        Checked::new(T::deserialize(deserializer)?).map(pass).map_err(|e| fail(e.tagged("")))
+    }
+}
+
+/// Serialized as the `T` itself.
+impl<T: Serialize, R> Serialize for Checked<T, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+/// Deserializes a field checked by the rule `R`, for `#[serde(deserialize_with = "...")]`, like
+/// `accord::serde::check::<Username, _, _>`.
+pub fn check<'de, R, T, D>(deserializer: D) -> Result<T, D::Error>
+    where R: Rule<T>,
+          T: Deserialize<'de>,
+          D: Deserializer<'de>
+{
+    Checked::<T, R>::deserialize(deserializer).map(Checked::into_inner)
+}
+
+/// Deserializes a `T`, giving the errors as a `MultipleError` tagged with the path to where
+/// deserialization failed, like `address.zip` or `tags[3]`. Keys that are not identifiers are
+/// tagged like `labels["no such label"]`, as by `each_value`. Map keys that are identifiers
+/// cannot be told apart from struct fields, so they are joined with `.` like fields are.
+///
+/// The errors of `Checked`, `check` and `Validated` are kept as they are, while other errors are
+/// the message of the Serde error with the code `accord.body.invalid_data`.
+///
+/// Serde errors can only carry a message, so the errors of `Checked`, `check` and `Validated` are
+/// handed over through a thread local while `deserialize` runs, and only used if the message of
+/// the final error starts with theirs. Otherwise, like when the format deserializes on another
+/// thread, changes the message, or an untagged enum recovered from them and failed later for
+/// another reason, the error is reported with the code `accord.body.invalid_data`.
+///
+/// ```
+/// # #[macro_use] extern crate accord;
+/// # extern crate serde_json;
+/// use accord::serde::Checked;
+/// use accord::validators::{alphanumeric, max};
+///
+/// rule!(Username: String => [max(16), alphanumeric()]);
+///
+/// #[derive(Debug, serde_derive::Deserialize)]
+/// struct Signup {
+///     username: Checked<String, Username>,
+///     #[serde(deserialize_with = "accord::serde::check::<Username, _, _>")]
+///     nickname: String,
+/// }
+///
+/// # fn main() {
+/// let json = r#"{ "username": "test", "nickname": "not valid!" }"#;
+/// let error = accord::serde::deserialize::<Signup, _>(
+///     &mut serde_json::Deserializer::from_str(json)).unwrap_err();
+///
+/// assert_eq!(error.0[0].tag, "nickname");
+/// assert_eq!(error.0[0].invalids[0].code, "accord.contain_only.invalid_char");
+/// # }
+/// ```
+#[cfg(feature = "serde_path_to_error")]
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, MultipleError>
+    where T: Deserialize<'de>,
+          D: Deserializer<'de>
+{
+    let outer = FAILED.with(|failed| failed.replace(Some(None)));
+    let result = serde_path_to_error::deserialize(deserializer);
+    let failed = FAILED.with(|failed| failed.replace(outer)).and_then(|failed| failed);
+    let error = match result {
+        Ok(value) => return Ok(value),
+        Err(error) => error,
+    };
+    let path = error.path().iter().fold(String::new(), |tag, segment| match *segment {
+        Segment::Seq { index } => join_tags(&tag, &format!("[{}]", index)),
+        Segment::Map { ref key } if is_identifier(key) => join_tags(&tag, key),
+        Segment::Map { ref key } => join_tags(&tag, &format!("[{:?}]", key)),
+        Segment::Enum { ref variant } => join_tags(&tag, variant),
+        Segment::Unknown => tag,
+    });
+    let msg = error.inner().to_string();
+    let invalids = match failed {
+        Some(failed) if msg.starts_with(&failed.msg) => failed.invalids,
+        _ => MultipleError::unreadable("accord.body.invalid_data", msg).0,
+    };
+    Err(MultipleError(invalids.into_iter()
+        .map(|m| MultipleInvalid { tag: join_tags(&path, &m.tag), invalids: m.invalids })
+        .collect()))
+}
+
+#[cfg(feature = "serde_path_to_error")]
+fn is_identifier(key: &str) -> bool {
+    key.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
@@ -1,18 +1,336 @@
-#[derive(Debug, Clone, Serialize)]
+use std::error;
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Invalid {
+    /// Stable, machine-readable identifier of the error, like `accord.length.too_short`.
+    ///
+    /// Empty when deserializing errors from before codes were added.
+    #[serde(default)]
+    pub code: String,
     pub msg: String,
-    pub args: Vec<String>,
+    pub args: Vec<Arg>,
 	pub human_readable: String
 }
 
-#[derive(Debug, Clone, Serialize)]
+impl Invalid {
+    /// The args as strings, like they were before args were typed.
+    pub fn string_args(&self) -> Vec<String> {
+        self.args.iter().map(|arg| arg.to_string()).collect()
+    }
+}
+
+/// An argument of an `Invalid`, filling in a placeholder like `%1` in its message.
+///
+/// Args are serialized as plain JSON values, except named args, which are serialized as
+/// `{"name": "min", "value": 1}`.
+///
+/// JSON does not tell signed and unsigned integers apart, so integers are deserialized as `Int`
+/// when they fit in an `i64` and as `UInt` otherwise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Arg {
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    List(Vec<Arg>),
+    Named { name: String, value: Box<Arg> },
+}
+
+impl Arg {
+    /// An arg with a name, like the `min` and `max` of a length.
+    pub fn named<T: ToArg + ?Sized>(name: &str, value: &T) -> Arg {
+        Arg::Named {
+            name: name.to_string(),
+            value: Box::new(value.to_arg()),
+        }
+    }
+
+    /// A list of args, like the allowed values of `either`.
+    pub fn list<'a, T: ToArg + 'a, I: IntoIterator<Item = &'a T>>(values: I) -> Arg {
+        Arg::List(values.into_iter().map(ToArg::to_arg).collect())
+    }
+}
+
+/// Lists are joined with `, ` and named args show only their value.
+impl fmt::Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Arg::Int(i) => write!(f, "{}", i),
+            Arg::UInt(u) => write!(f, "{}", u),
+            Arg::Float(x) => write!(f, "{}", x),
+            Arg::Bool(b) => write!(f, "{}", b),
+            Arg::Str(ref s) => write!(f, "{}", s),
+            Arg::List(ref list) => {
+                for (i, arg) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                Ok(())
+            }
+            Arg::Named { ref value, .. } => write!(f, "{}", value),
+        }
+    }
+}
+
+impl<'a, T: ToArg + ?Sized> From<&'a T> for Arg {
+    fn from(value: &'a T) -> Arg {
+        value.to_arg()
+    }
+}
+
+impl From<String> for Arg {
+    fn from(value: String) -> Arg {
+        Arg::Str(value)
+    }
+}
+
+/// Values that can be given as the args of an `Invalid`, e.g. by `range` and `max_items`.
+///
+/// Validators taking any `Display` value, like `eq` and `either`, give it as an `Arg::Str`.
+pub trait ToArg {
+    fn to_arg(&self) -> Arg;
+}
+
+macro_rules! to_arg {
+    ( $variant:ident as $as:ty: $( $t:ty ),* ) => {
+        $(impl ToArg for $t {
+            fn to_arg(&self) -> Arg {
+                Arg::$variant(*self as $as)
+            }
+        })*
+    };
+}
+
+to_arg!(Int as i64: i8, i16, i32, i64, isize);
+to_arg!(UInt as u64: u8, u16, u32, u64, usize);
+to_arg!(Float as f64: f32, f64);
+
+impl ToArg for bool {
+    fn to_arg(&self) -> Arg {
+        Arg::Bool(*self)
+    }
+}
+
+impl ToArg for char {
+    fn to_arg(&self) -> Arg {
+        Arg::Str(self.to_string())
+    }
+}
+
+impl ToArg for str {
+    fn to_arg(&self) -> Arg {
+        Arg::Str(self.to_string())
+    }
+}
+
+impl ToArg for String {
+    fn to_arg(&self) -> Arg {
+        Arg::Str(self.clone())
+    }
+}
+
+impl<T: ToArg + ?Sized> ToArg for &T {
+    fn to_arg(&self) -> Arg {
+        (**self).to_arg()
+    }
+}
+
+impl<T: ToArg> ToArg for [T] {
+    fn to_arg(&self) -> Arg {
+        Arg::list(self)
+    }
+}
+
+impl<T: ToArg> ToArg for Vec<T> {
+    fn to_arg(&self) -> Arg {
+        Arg::list(self)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultipleInvalid {
     pub tag: String,
     pub invalids: Vec<Invalid>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+impl MultipleInvalid {
+    /// The segments of the tag, e.g. `address.zip` gives `["address", "zip"]` and
+    /// `labels["env"][0]` gives `["labels", "env", 0]`.
+    pub fn path(&self) -> Vec<PathSegment> {
+        let mut path = Vec::new();
+        let mut key = String::new();
+        let mut chars = self.tag.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' | '[' => {
+                    if !key.is_empty() {
+                        path.push(PathSegment::Key(key.clone()));
+                        key.clear();
+                    }
+                    if c == '.' {
+                        continue;
+                    }
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        // keys are tagged with their `Debug` representation
+                        while let Some(c) = chars.next() {
+                            match c {
+                                '"' => break,
+                                '\\' => match chars.next() {
+                                    Some('n') => key.push('\n'),
+                                    Some('r') => key.push('\r'),
+                                    Some('t') => key.push('\t'),
+                                    Some(c) => key.push(c),
+                                    None => {}
+                                },
+                                c => key.push(c),
+                            }
+                        }
+                        path.push(PathSegment::Key(key.clone()));
+                        key.clear();
+                        chars.next();
+                    } else {
+                        let index = chars.by_ref().take_while(|c| *c != ']').collect::<String>();
+                        path.push(match index.parse() {
+                            Ok(i) => PathSegment::Index(i),
+                            Err(_) => PathSegment::Key(index),
+                        });
+                    }
+                }
+                c => key.push(c),
+            }
+        }
+        if !key.is_empty() {
+            path.push(PathSegment::Key(key));
+        }
+        path
+    }
+}
+
+/// A segment of the path to some invalid data, like the name of a field or the index of an
+/// element.
+///
+/// Serialized as a JSON string or number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PathSegment {
+    Index(usize),
+    Key(String),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PathSegment::Index(i) => write!(f, "{}", i),
+            PathSegment::Key(ref key) => write!(f, "{}", key),
+        }
+    }
+}
+
+/// Serialized as the list of its `Invalid`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Error(pub Vec<Invalid>);
 
-#[derive(Debug, Clone, Serialize)]
+impl Error {
+    /// The errors as a `MultipleError` for the single field `tag`.
+    pub fn tagged(self, tag: &str) -> MultipleError {
+        MultipleError(vec![MultipleInvalid {
+            tag: tag.to_string(),
+            invalids: self.0,
+        }])
+    }
+}
+
+/// Serialized as a list of tags with their `Invalid`s:
+///
+/// ```json
+/// [
+///     {
+///         "tag": "age",
+///         "invalids": [
+///             {
+///                 "code": "accord.range.out_of_range",
+///                 "msg": "Must be in the range %1..%2.",
+///                 "args": [{ "name": "min", "value": 12 }, { "name": "max", "value": 127 }],
+///                 "human_readable": "Must be between 12 and 127"
+///             }
+///         ]
+///     }
+/// ]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultipleError(pub Vec<MultipleInvalid>);
+
+impl MultipleError {
+    /// An error for data that could not be deserialized, like a malformed request body, with an
+    /// empty tag and `reason` as its only argument.
+    pub fn unreadable(code: &str, reason: String) -> MultipleError {
+        MultipleError(vec![MultipleInvalid {
+            tag: String::new(),
+            invalids: vec![Invalid {
+                code: code.to_string(),
+                msg: "Could not read the data: %1.".to_string(),
+                args: vec![Arg::Str(reason.clone())],
+                human_readable: reason,
+            }],
+        }])
+    }
+}
+
+impl fmt::Display for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.human_readable)
+    }
+}
+
+/// One line per error, like `email: Must contain '@'`.
+impl fmt::Display for MultipleInvalid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, invalid) in self.invalids.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            if self.tag.is_empty() {
+                write!(f, "{}", invalid)?;
+            } else {
+                write!(f, "{}: {}", self.tag, invalid)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One line per error.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, invalid) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", invalid)?;
+        }
+        Ok(())
+    }
+}
+
+/// One line per error, like `email: Must contain '@'`.
+impl fmt::Display for MultipleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, multiple_invalid) in self.0.iter().filter(|m| !m.invalids.is_empty()).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", multiple_invalid)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Error {}
+
+impl error::Error for MultipleError {}
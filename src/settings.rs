@@ -0,0 +1,113 @@
+use crate::{push_invalids, Invalid, MultipleError, MultipleInvalid, Outcome};
+
+/// When to stop running validators.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FailFast {
+    /// Run every validator and collect every error.
+    #[default]
+    Never,
+    /// Stop running the validators of a piece of data once one of them has failed.
+    PerField,
+    /// Stop running validators altogether once one of them has failed.
+    Global,
+}
+
+/// Settings that control how much work the collection form of `rules!` does.
+///
+/// The default settings run every validator and collect every error, like `rules!` without
+/// settings does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Settings {
+    pub fail_fast: FailFast,
+    /// Stop running validators once this many `Invalid`s have been collected.
+    pub max_errors: Option<usize>,
+}
+
+impl Settings {
+    /// Drops the errors of an already computed `result` that would not have been collected
+    /// under these settings, treating every tag as a piece of data of its own.
+    pub fn apply(&self, result: crate::Result) -> crate::Result {
+        let mut collector = Collector::new(self);
+        if let Err(MultipleError(multiple_invalids)) = result {
+            for m in multiple_invalids {
+                collector.begin_field();
+                for invalid in m.invalids {
+                    if collector.proceed() {
+                        collector.collect(Err::<(), Invalid>(invalid), &m.tag);
+                    }
+                }
+            }
+        }
+        collector.finish()
+    }
+}
+
+/// Collects the errors found by the collection form of `rules!`, according to `Settings`.
+///
+/// Validators running other validators, like `each`, use it to honour the settings they are
+/// given through `Validators::validate_into`.
+pub struct Collector<'a> {
+    settings: &'a Settings,
+    invalids: Vec<MultipleInvalid>,
+    count: usize,
+    field_failed: bool,
+}
+
+impl<'a> Collector<'a> {
+    pub fn new(settings: &'a Settings) -> Collector<'a> {
+        Collector {
+            settings,
+            invalids: Vec::new(),
+            count: 0,
+            field_failed: false,
+        }
+    }
+
+    /// The settings errors are collected by.
+    pub fn settings(&self) -> &'a Settings {
+        self.settings
+    }
+
+    /// Starts collecting the errors of the next piece of data.
+    pub fn begin_field(&mut self) {
+        self.field_failed = false;
+    }
+
+    /// Whether the next validator should run at all.
+    pub fn proceed(&self) -> bool {
+        if self.settings.max_errors.is_some_and(|max| self.count >= max) {
+            return false;
+        }
+        match self.settings.fail_fast {
+            FailFast::Never => true,
+            FailFast::PerField => !self.field_failed,
+            FailFast::Global => self.count == 0,
+        }
+    }
+
+    /// Adds the errors of `outcome` under `tag`, up to `max_errors`.
+    pub fn collect<O: Outcome>(&mut self, outcome: O, tag: &str) {
+        let mut found = Vec::new();
+        outcome.collect(tag, &mut found);
+        for m in found {
+            let mut invalids = m.invalids;
+            if let Some(max) = self.settings.max_errors {
+                invalids.truncate(max.saturating_sub(self.count));
+            }
+            if invalids.is_empty() {
+                continue;
+            }
+            self.count += invalids.len();
+            self.field_failed = true;
+            push_invalids(&mut self.invalids, &m.tag, invalids);
+        }
+    }
+
+    pub fn finish(self) -> crate::Result {
+        if self.invalids.is_empty() {
+            Ok(())
+        } else {
+            Err(MultipleError(self.invalids))
+        }
+    }
+}
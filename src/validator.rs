@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+
+use crate::validators::{self, WithMsg};
+use crate::{Collector, MultipleError, MultipleInvalid, Outcome, Settings};
+
+/// A rule that data of type `T` can be validated against.
+///
+/// The functions in `validators` build validators that can be created once, e.g. in a `static`,
+/// and run without allocating:
+///
+/// ```
+/// extern crate accord;
+///
+/// use accord::Validator;
+/// use accord::validators::{contains, Contains};
+///
+/// static AT: Contains = contains("@");
+///
+/// fn main() {
+///     assert!(AT.validate(&"test@test.test".to_string()).is_ok());
+/// }
+/// ```
+///
+/// Any closure taking a reference to the data and returning an `Outcome` is a validator as well.
+pub trait Validator<T: ?Sized> {
+    type Output: Outcome;
+
+    fn validate(&self, value: &T) -> Self::Output;
+
+    /// Validates according to `settings`, as done by the settings form of `rules!`.
+    ///
+    /// By default this is `validate`. Validators running other validators, like `nested` and
+    /// `each`, pass `settings` on to them, so they stop early as well.
+    fn validate_with_settings(&self, value: &T, _settings: &Settings) -> Self::Output {
+        self.validate(value)
+    }
+}
+
+impl<T: ?Sized, O: Outcome, F: Fn(&T) -> O> Validator<T> for F {
+    type Output = O;
+
+    fn validate(&self, value: &T) -> O {
+        self(value)
+    }
+}
+
+/// Methods for adjusting any validator, e.g. `length(1, 64).message("Pick a shorter name.")`.
+pub trait ValidatorExt: Sized {
+    /// See `validators::message`.
+    fn message<M: Into<Cow<'static, str>>>(self, msg: M) -> WithMsg<Self> {
+        validators::message(self, msg)
+    }
+
+    /// See `validators::with_msg`.
+    fn with_msg<C, M, H>(self, code: C, msg: M, human_readable: H) -> WithMsg<Self>
+        where C: Into<Cow<'static, str>>,
+              M: Into<Cow<'static, str>>,
+              H: Into<Cow<'static, str>>
+    {
+        validators::with_msg(self, code, msg, human_readable)
+    }
+}
+
+impl<V> ValidatorExt for V {}
+
+/// A list of validators, like a `Vec`, an array or a tuple of validators.
+///
+/// Use a tuple when the validators are of different types, e.g. `(length(1, 32), alphanumeric())`.
+pub trait Validators<T: ?Sized> {
+    /// Runs every validator on `value`, calling `f` with the invalids of each of them in turn.
+    fn validate_each(&self, value: &T, f: &mut dyn FnMut(Vec<MultipleInvalid>));
+
+    /// Runs the validators on `value` while `collector` proceeds, adding their errors under `tag`.
+    fn validate_into(&self, value: &T, collector: &mut Collector, tag: &str) {
+        self.validate_each(value, &mut |found| {
+            if collector.proceed() {
+                collector.collect(Err::<(), _>(MultipleError(found)), tag);
+            }
+        });
+    }
+}
+
+impl<T: ?Sized, V: Validator<T>> Validators<T> for Vec<V> {
+    fn validate_each(&self, value: &T, f: &mut dyn FnMut(Vec<MultipleInvalid>)) {
+        for validator in self {
+            f(invalids(validator.validate(value)));
+        }
+    }
+
+    fn validate_into(&self, value: &T, collector: &mut Collector, tag: &str) {
+        for validator in self {
+            if collector.proceed() {
+                let settings = collector.settings();
+                collector.collect(validator.validate_with_settings(value, settings), tag);
+            }
+        }
+    }
+}
+
+impl<T: ?Sized, V: Validator<T>, const N: usize> Validators<T> for [V; N] {
+    fn validate_each(&self, value: &T, f: &mut dyn FnMut(Vec<MultipleInvalid>)) {
+        for validator in self {
+            f(invalids(validator.validate(value)));
+        }
+    }
+
+    fn validate_into(&self, value: &T, collector: &mut Collector, tag: &str) {
+        for validator in self {
+            if collector.proceed() {
+                let settings = collector.settings();
+                collector.collect(validator.validate_with_settings(value, settings), tag);
+            }
+        }
+    }
+}
+
+macro_rules! tuple_validators {
+    ( $( $v:ident . $i:tt ),+ ) => {
+        impl<T: ?Sized, $( $v: Validator<T> ),+> Validators<T> for ( $( $v, )+ ) {
+            fn validate_each(&self, value: &T, f: &mut dyn FnMut(Vec<MultipleInvalid>)) {
+                $( f(invalids(self.$i.validate(value))); )+
+            }
+
+            fn validate_into(&self, value: &T, collector: &mut Collector, tag: &str) {
+                $(if collector.proceed() {
+                    let settings = collector.settings();
+                    collector.collect(self.$i.validate_with_settings(value, settings), tag);
+                })+
+            }
+        }
+    };
+}
+
+tuple_validators!(A.0);
+tuple_validators!(A.0, B.1);
+tuple_validators!(A.0, B.1, C.2);
+tuple_validators!(A.0, B.1, C.2, D.3);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4, F.5);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4, F.5, G.6);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10);
+tuple_validators!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10, L.11);
+
+/// Collects the errors of `outcome` with tags relative to the validated data.
+pub fn invalids<O: Outcome>(outcome: O) -> Vec<MultipleInvalid> {
+    let mut invalids = Vec::new();
+    outcome.collect("", &mut invalids);
+    invalids
+}
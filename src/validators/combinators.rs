@@ -0,0 +1,216 @@
+use crate::validator::invalids;
+use crate::{Arg, ToArg, Validator, Validators};
+
+/// Enforce that `T` passes all of `validators`.
+///
+/// The reasons of every failing validator are given as the arguments of the error.
+pub const fn all_of<V>(validators: V) -> AllOf<V> {
+    AllOf { validators }
+}
+
+/// Validator built by `all_of`.
+#[derive(Debug, Clone)]
+pub struct AllOf<V> {
+    validators: V,
+}
+
+impl<T: ?Sized, V: Validators<T>> Validator<T> for AllOf<V> {
+    type Output = crate::ValidatorResult;
+
+    fn validate(&self, s: &T) -> crate::ValidatorResult {
+        let mut reasons = Vec::new();
+        self.validators.validate_each(s, &mut |found| reasons.extend(self::reasons(found)));
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Invalid {
+                code: "accord.all_of.failed".to_string(),
+                msg: "Must satisfy all of the given rules.".to_string(),
+                human_readable: format!("Must satisfy all of: {}", reasons.join("; ")),
+                args: reasons.into_iter().map(Arg::Str).collect(),
+            })
+        }
+    }
+}
+
+/// Enforce that `T` passes at least one of `validators`.
+///
+/// The reasons of every validator are given as the arguments of the error.
+pub const fn any_of<V>(validators: V) -> AnyOf<V> {
+    AnyOf { validators }
+}
+
+/// Validator built by `any_of`.
+#[derive(Debug, Clone)]
+pub struct AnyOf<V> {
+    validators: V,
+}
+
+impl<T: ?Sized, V: Validators<T>> Validator<T> for AnyOf<V> {
+    type Output = crate::ValidatorResult;
+
+    fn validate(&self, s: &T) -> crate::ValidatorResult {
+        let mut passed = false;
+        let mut reasons = Vec::new();
+        self.validators.validate_each(s, &mut |found| {
+            passed |= found.is_empty();
+            reasons.extend(self::reasons(found));
+        });
+        if passed {
+            Ok(())
+        } else {
+            Err(crate::Invalid {
+                code: "accord.any_of.failed".to_string(),
+                msg: "Must satisfy at least one of the given rules.".to_string(),
+                human_readable: format!("Must satisfy at least one of: {}", reasons.join("; ")),
+                args: reasons.into_iter().map(Arg::Str).collect(),
+            })
+        }
+    }
+}
+
+/// Enforce that `T` passes none of `validators`.
+///
+/// The positions of the validators that passed are given as the arguments of the error.
+pub const fn none_of<V>(validators: V) -> NoneOf<V> {
+    NoneOf { validators }
+}
+
+/// Validator built by `none_of`.
+#[derive(Debug, Clone)]
+pub struct NoneOf<V> {
+    validators: V,
+}
+
+impl<T: ?Sized, V: Validators<T>> Validator<T> for NoneOf<V> {
+    type Output = crate::ValidatorResult;
+
+    fn validate(&self, s: &T) -> crate::ValidatorResult {
+        let mut i = 0usize;
+        let mut passed = Vec::new();
+        self.validators.validate_each(s, &mut |found| {
+            if found.is_empty() {
+                passed.push(i);
+            }
+            i += 1;
+        });
+        if passed.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Invalid {
+                code: "accord.none_of.matched".to_string(),
+                msg: "Must not satisfy any of the given rules.".to_string(),
+                human_readable: format!("Must not satisfy rule {}", Arg::list(&passed)),
+                args: passed.iter().map(ToArg::to_arg).collect(),
+            })
+        }
+    }
+}
+
+/// Enforce that `T` does not pass `validator`.
+pub const fn not<V>(validator: V) -> Not<V> {
+    Not { validator }
+}
+
+/// Validator built by `not`.
+#[derive(Debug, Clone)]
+pub struct Not<V> {
+    validator: V,
+}
+
+impl<T: ?Sized, V: Validator<T>> Validator<T> for Not<V> {
+    type Output = crate::ValidatorResult;
+
+    fn validate(&self, s: &T) -> crate::ValidatorResult {
+        if !invalids(self.validator.validate(s)).is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Invalid {
+                code: "accord.not.matched".to_string(),
+                msg: "Must not satisfy the given rule.".to_string(),
+                args: vec![],
+                human_readable: "Must not satisfy the given rule".to_string()
+            })
+        }
+    }
+}
+
+/// The reasons of the errors a validator found.
+fn reasons(found: Vec<crate::MultipleInvalid>) -> Vec<String> {
+    found.into_iter()
+        .flat_map(|m| m.invalids)
+        .map(|invalid| invalid.human_readable)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators::{contains, max, min, Contains};
+
+    // all_of
+    #[test]
+    pub fn all_of_valid() {
+        assert!(all_of([contains("@"), contains(".")]).validate(&"a@b.c".to_owned()).is_ok());
+        assert!(all_of(Vec::<Contains>::new()).validate(&"".to_owned()).is_ok());
+    }
+
+    #[test]
+    pub fn all_of_invalid() {
+        let invalid = all_of((contains("@"), contains("."), max(3))).validate(&"a@bc".to_owned())
+            .unwrap_err();
+        assert_eq!(invalid.args.len(), 2);
+    }
+
+    // any_of
+    #[test]
+    pub fn any_of_valid() {
+        assert!(any_of([contains("@"), contains("+")]).validate(&"a@b".to_owned()).is_ok());
+        assert!(any_of([contains("@"), contains("+")]).validate(&"+45".to_owned()).is_ok());
+    }
+
+    #[test]
+    pub fn any_of_invalid() {
+        let invalid = any_of([contains("@"), contains("+")]).validate(&"foo".to_owned()).unwrap_err();
+        assert_eq!(invalid.args,
+                   vec![Arg::from("Must contain '@'"), Arg::from("Must contain '+'")]);
+        assert!(any_of(Vec::<Contains>::new()).validate(&"".to_owned()).is_err());
+    }
+
+    // none_of
+    #[test]
+    pub fn none_of_valid() {
+        assert!(none_of([contains("admin"), contains("root")]).validate(&"user".to_owned()).is_ok());
+    }
+
+    #[test]
+    pub fn none_of_invalid() {
+        let invalid = none_of([contains("admin"), contains("root")]).validate(&"root".to_owned())
+            .unwrap_err();
+        assert_eq!(invalid.args, vec![Arg::UInt(1)]);
+    }
+
+    // not
+    #[test]
+    pub fn not_valid() {
+        assert!(not(contains("admin")).validate(&"user".to_owned()).is_ok());
+    }
+
+    #[test]
+    pub fn not_invalid() {
+        assert!(not(contains("admin")).validate(&"admin".to_owned()).is_err());
+    }
+
+    // nesting
+    #[test]
+    pub fn nested_combinators() {
+        let email_or_phone = || {
+            any_of((all_of([contains("@"), contains(".")]),
+                        all_of((min(8), max(12), not(contains("@"))))))
+        };
+        assert!(email_or_phone().validate(&"a@b.c".to_owned()).is_ok());
+        assert!(email_or_phone().validate(&"+4512345678".to_owned()).is_ok());
+        assert!(email_or_phone().validate(&"a@b".to_owned()).is_err());
+        assert!(email_or_phone().validate(&"1234".to_owned()).is_err());
+    }
+}
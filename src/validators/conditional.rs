@@ -0,0 +1,99 @@
+use crate::{Collector, Settings, Validator, Validators};
+
+/// Run `validators` only if `condition` holds. Always ok otherwise.
+///
+/// `condition` is either a predicate on the validated value, e.g.
+/// `when(|s: &Shipment| s.express, vec![either(carriers)])` in the `self => [...]` form, or a
+/// `bool`. Since `rules!` builds its validators every time it runs, a `bool` can depend on other
+/// data, e.g. `when(self.account_type == AccountType::Business, (length(1, 64), alphanumeric()))`.
+pub const fn when<C, V>(condition: C, validators: V) -> When<C, V> {
+    When { condition, expected: true, validators }
+}
+
+/// Run `validators` only if `condition` does not hold. Always ok otherwise.
+pub const fn unless<C, V>(condition: C, validators: V) -> When<C, V> {
+    When { condition, expected: false, validators }
+}
+
+/// Condition of `when` and `unless`, either a `bool` or a `Fn(&T) -> bool`.
+pub trait Condition<T: ?Sized> {
+    fn holds(&self, value: &T) -> bool;
+}
+
+impl<T: ?Sized> Condition<T> for bool {
+    fn holds(&self, _: &T) -> bool {
+        *self
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T) -> bool> Condition<T> for F {
+    fn holds(&self, value: &T) -> bool {
+        self(value)
+    }
+}
+
+/// Validator built by `when` and `unless`.
+#[derive(Debug, Clone, Copy)]
+pub struct When<C, V> {
+    condition: C,
+    expected: bool,
+    validators: V,
+}
+
+impl<T: ?Sized, C: Condition<T>, V: Validators<T>> Validator<T> for When<C, V> {
+    type Output = crate::Result;
+
+    fn validate(&self, s: &T) -> crate::Result {
+        self.validate_with_settings(s, &Settings::default())
+    }
+
+    fn validate_with_settings(&self, s: &T, settings: &Settings) -> crate::Result {
+        let mut collector = Collector::new(settings);
+        if self.condition.holds(s) == self.expected {
+            collector.begin_field();
+            self.validators.validate_into(s, &mut collector, "");
+        }
+        collector.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators::{contains, max};
+
+    fn invalids(result: crate::Result) -> usize {
+        result.err().map_or(0, |e| e.0.into_iter().map(|m| m.invalids.len()).sum())
+    }
+
+    // when
+    #[test]
+    pub fn when_valid() {
+        assert_eq!(invalids(when(true, (max(3), contains("b"))).validate(&"bar".to_owned())), 0);
+        assert_eq!(invalids(when(false, (max(3), contains("b"))).validate(&"foobaz".to_owned())), 0);
+        assert_eq!(invalids(when(String::is_empty, vec![contains("b")]).validate(&"foo".to_owned())), 0);
+    }
+
+    #[test]
+    pub fn when_invalid() {
+        assert_eq!(invalids(when(true, (max(3), contains("b"))).validate(&"foo".to_owned())), 1);
+        assert_eq!(invalids(when(true, (max(3), contains("b"))).validate(&"foofoo".to_owned())), 2);
+        assert_eq!(invalids(when(String::is_empty, vec![contains("b")]).validate(&"".to_owned())), 1);
+    }
+
+    // unless
+    #[test]
+    pub fn unless_valid() {
+        assert_eq!(invalids(unless(false, vec![max(3)]).validate(&"foo".to_owned())), 0);
+        assert_eq!(invalids(unless(true, vec![max(3)]).validate(&"foobar".to_owned())), 0);
+        assert_eq!(invalids(unless(|s: &String| s.len() > 3, vec![max(3)])
+            .validate(&"foobar".to_owned())), 0);
+    }
+
+    #[test]
+    pub fn unless_invalid() {
+        assert_eq!(invalids(unless(false, vec![max(3)]).validate(&"foobar".to_owned())), 1);
+        assert_eq!(invalids(unless(|s: &String| s.is_empty(), vec![max(3)])
+            .validate(&"foobar".to_owned())), 1);
+    }
+}
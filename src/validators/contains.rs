@@ -1,72 +1,126 @@
+use crate::{ToArg, Validator, ValidatorResult};
+
 /// Enforce that a string must contain `needle`.
-pub fn contains(needle: &'static str) -> Box<Fn(&String) -> ::ValidatorResult> {
-    Box::new(move |s: &String| {
-        if s.contains(needle) {
+pub const fn contains(needle: &'static str) -> Contains {
+    Contains { needle }
+}
+
+/// Validator built by `contains`.
+#[derive(Debug, Clone, Copy)]
+pub struct Contains {
+    needle: &'static str,
+}
+
+impl Validator<String> for Contains {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        if s.contains(self.needle) {
             Ok(())
         } else {
-            Err(::Invalid {
+            Err(crate::Invalid {
+                code: "accord.contains.missing".to_string(),
                 msg: "Must contain %1.".to_string(),
-                args: vec![needle.to_string()],
-				human_readable: format!("Must contain '{}'", needle)
+                args: vec![self.needle.to_arg()],
+				human_readable: format!("Must contain '{}'", self.needle)
             })
         }
-    })
+    }
 }
 
 /// Enforce that a string contains only characters in `accepted`
-pub fn contain_only(accepted: &'static [char]) -> Box<Fn(&String) -> ::ValidatorResult> {
-    Box::new(move |s: &String| {
+pub const fn contain_only(accepted: &'static [char]) -> ContainOnly {
+    ContainOnly { accepted }
+}
+
+/// Validator built by `contain_only`.
+#[derive(Debug, Clone, Copy)]
+pub struct ContainOnly {
+    accepted: &'static [char],
+}
+
+impl Validator<String> for ContainOnly {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
         for c in s.chars() {
-			if !accepted.contains(&c) {
-				return Err(::Invalid {
+			if !self.accepted.contains(&c) {
+				return Err(crate::Invalid {
+					code: "accord.contain_only.invalid_char".to_string(),
 					msg: "Must not contain %1.".to_string(),
-					args: vec![c.to_string()],
+					args: vec![c.to_arg()],
 					human_readable: format!("Must not contain '{}'", c)
 				});
 			}
 		}
 		Ok(())
-    })
+    }
 }
 
 /// Enforce that a string must not contain `needle`.
-pub fn not_contain(needle: &'static str) -> Box<Fn(&String) -> ::ValidatorResult> {
-    Box::new(move |s: &String| {
-        if !s.contains(needle) {
+pub const fn not_contain(needle: &'static str) -> NotContain {
+    NotContain { needle }
+}
+
+/// Validator built by `not_contain`.
+#[derive(Debug, Clone, Copy)]
+pub struct NotContain {
+    needle: &'static str,
+}
+
+impl Validator<String> for NotContain {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        if !s.contains(self.needle) {
             Ok(())
         } else {
-            Err(::Invalid {
+            Err(crate::Invalid {
+                code: "accord.not_contain.present".to_string(),
                 msg: "Must not contain %1.".to_string(),
-                args: vec![needle.to_string()],
-                human_readable: format!("Must not contain '{}'", needle)
+                args: vec![self.needle.to_arg()],
+                human_readable: format!("Must not contain '{}'", self.needle)
             })
         }
-    })
+    }
 }
 
 /// Enforce that a string must not contain any of `needles`.
-pub fn not_contain_any(needles: &'static [&'static str]) -> Box<Fn(&String) -> ::ValidatorResult> {
-    Box::new(move |s: &String| {
-        for needle in needles {
+pub const fn not_contain_any(needles: &'static [&'static str]) -> NotContainAny {
+    NotContainAny { needles }
+}
+
+/// Validator built by `not_contain_any`.
+#[derive(Debug, Clone, Copy)]
+pub struct NotContainAny {
+    needles: &'static [&'static str],
+}
+
+impl Validator<String> for NotContainAny {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        for needle in self.needles {
             if s.contains(needle) {
-                return Err(::Invalid {
+                return Err(crate::Invalid {
+                    code: "accord.not_contain_any.present".to_string(),
                     msg: "Must not contain %1.".to_string(),
-                    args: vec![needle.to_string()],
+                    args: vec![needle.to_arg()],
                     human_readable: format!("Must not contain '{}'", needle)
                 });
             }
         }
         Ok(())
-    })
+    }
 }
 
 /// Convenience function; 0-9, A-z
-pub fn alphanumeric() -> Box<Fn(&String) -> ::ValidatorResult> {
+pub const fn alphanumeric() -> ContainOnly {
     contain_only(&['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z'])
 }
 
 /// Convenience function; Alphanumeric & underscore.
-pub fn alphanumeric_dashes() -> Box<Fn(&String) -> ::ValidatorResult> {
+pub const fn alphanumeric_dashes() -> ContainOnly {
     contain_only(&['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '_', '-'])
 }
 
@@ -77,63 +131,76 @@ mod tests {
     // contains
     #[test]
     pub fn contains_valid() {
-        assert!(contains("bar")(&"foobar".to_owned()).is_ok());
-        assert!(contains("bar")(&"barbaz".to_owned()).is_ok());
-        assert!(contains("bar")(&"bar".to_owned()).is_ok());
+        assert!(contains("bar").validate(&"foobar".to_owned()).is_ok());
+        assert!(contains("bar").validate(&"barbaz".to_owned()).is_ok());
+        assert!(contains("bar").validate(&"bar".to_owned()).is_ok());
     }
 
     #[test]
     pub fn contains_invalid() {
-        assert!(contains("bar")(&"foo".to_owned()).is_err());
-        assert!(contains("bar")(&"baz".to_owned()).is_err());
-        assert!(contains("bar")(&"ba".to_owned()).is_err());
+        assert!(contains("bar").validate(&"foo".to_owned()).is_err());
+        assert!(contains("bar").validate(&"baz".to_owned()).is_err());
+        assert!(contains("bar").validate(&"ba".to_owned()).is_err());
     }
 
     // contain_only
     #[test]
     pub fn contain_only_valid() {
-        assert!(contain_only(&['a', 'b', 'c'])(&"aaaaaaaa".to_owned()).is_ok());
-        assert!(contain_only(&['a', 'b', 'c'])(&"bbbbbbbb".to_owned()).is_ok());
-        assert!(contain_only(&['a', 'b', 'c'])(&"cccccccc".to_owned()).is_ok());
-        assert!(contain_only(&['a', 'b', 'c'])(&"abcabcab".to_owned()).is_ok());
+        assert!(contain_only(&['a', 'b', 'c']).validate(&"aaaaaaaa".to_owned()).is_ok());
+        assert!(contain_only(&['a', 'b', 'c']).validate(&"bbbbbbbb".to_owned()).is_ok());
+        assert!(contain_only(&['a', 'b', 'c']).validate(&"cccccccc".to_owned()).is_ok());
+        assert!(contain_only(&['a', 'b', 'c']).validate(&"abcabcab".to_owned()).is_ok());
     }
 
     #[test]
     pub fn contain_only_invalid() {
-        assert!(contain_only(&['a', 'b', 'c'])(&"abcdefghijklmnopqrstuvwxyz".to_owned()).is_err());
-        assert!(contain_only(&['a', 'b', 'c'])(&"abacus".to_owned()).is_err());
-        assert!(contain_only(&['a', 'b', 'c'])(&"sphinx of black quartz, judge my vow".to_owned()).is_err());
+        assert!(contain_only(&['a', 'b', 'c']).validate(&"abcdefghijklmnopqrstuvwxyz".to_owned()).is_err());
+        assert!(contain_only(&['a', 'b', 'c']).validate(&"abacus".to_owned()).is_err());
+        assert!(contain_only(&['a', 'b', 'c']).validate(&"sphinx of black quartz, judge my vow".to_owned()).is_err());
+    }
+
+    #[test]
+    pub fn contain_only_code() {
+        let invalid = contain_only(&['a']).validate(&"ab".to_owned()).unwrap_err();
+        assert_eq!(invalid.code, "accord.contain_only.invalid_char");
+        assert_eq!(invalid.msg, not_contain("b").validate(&"ab".to_owned()).unwrap_err().msg);
     }
 
     // not_contain
     #[test]
     pub fn not_contain_valid() {
-        assert!(not_contain("bar")(&"foobaz".to_owned()).is_ok());
-        assert!(not_contain("bar")(&"foo".to_owned()).is_ok());
-        assert!(not_contain("bar")(&"baz".to_owned()).is_ok());
+        assert!(not_contain("bar").validate(&"foobaz".to_owned()).is_ok());
+        assert!(not_contain("bar").validate(&"foo".to_owned()).is_ok());
+        assert!(not_contain("bar").validate(&"baz".to_owned()).is_ok());
+    }
+
+    #[test]
+    pub fn not_contain_code() {
+        let invalid = not_contain("b").validate(&"ab".to_owned()).unwrap_err();
+        assert_eq!(invalid.code, "accord.not_contain.present");
     }
 
     #[test]
     pub fn not_contain_invalid() {
-        assert!(not_contain("bar")(&"foobarbaz".to_owned()).is_err());
-        assert!(not_contain("bar")(&"bar".to_owned()).is_err());
-        assert!(not_contain("bar")(&"embark".to_owned()).is_err());
+        assert!(not_contain("bar").validate(&"foobarbaz".to_owned()).is_err());
+        assert!(not_contain("bar").validate(&"bar".to_owned()).is_err());
+        assert!(not_contain("bar").validate(&"embark".to_owned()).is_err());
     }
     
     // not_contain_any
         #[test]
     pub fn not_contain_any_valid() {
-        assert!(not_contain_any(&["foo", "bar", "baz"])(&"xyzzyx".to_owned()).is_ok());
-        assert!(not_contain_any(&["foo", "bar", "baz"])(&"asdfghjkl".to_owned()).is_ok());
-        assert!(not_contain_any(&["foo", "bar", "baz"])(&"takyon".to_owned()).is_ok());
+        assert!(not_contain_any(&["foo", "bar", "baz"]).validate(&"xyzzyx".to_owned()).is_ok());
+        assert!(not_contain_any(&["foo", "bar", "baz"]).validate(&"asdfghjkl".to_owned()).is_ok());
+        assert!(not_contain_any(&["foo", "bar", "baz"]).validate(&"takyon".to_owned()).is_ok());
     }
 
     #[test]
     pub fn not_contain_any_invalid() {
-        assert!(not_contain_any(&["foo", "bar", "baz"])(&"foo".to_owned()).is_err());
-        assert!(not_contain_any(&["foo", "bar", "baz"])(&"bar".to_owned()).is_err());
-        assert!(not_contain_any(&["foo", "bar", "baz"])(&"baz".to_owned()).is_err());
-        assert!(not_contain_any(&["foo", "bar", "baz"])(&"foobarbaz".to_owned()).is_err());
+        assert!(not_contain_any(&["foo", "bar", "baz"]).validate(&"foo".to_owned()).is_err());
+        assert!(not_contain_any(&["foo", "bar", "baz"]).validate(&"bar".to_owned()).is_err());
+        assert!(not_contain_any(&["foo", "bar", "baz"]).validate(&"baz".to_owned()).is_err());
+        assert!(not_contain_any(&["foo", "bar", "baz"]).validate(&"foobarbaz".to_owned()).is_err());
     }
     
 }
\ No newline at end of file
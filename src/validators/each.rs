@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+
+use crate::{Collector, Settings, Validator, Validators};
+
+/// Enforce that every element of a sequence passes all of `validators`.
+///
+/// Errors are tagged with the index of the element, so in the collection form of `rules!`
+/// they end up tagged like `tags[3]`.
+pub const fn each<V>(validators: V) -> Each<V> {
+    Each { validators }
+}
+
+/// Validator built by `each`.
+#[derive(Debug, Clone)]
+pub struct Each<V> {
+    validators: V,
+}
+
+impl<T, V: Validators<T>> Validator<[T]> for Each<V> {
+    type Output = crate::Result;
+
+    fn validate(&self, s: &[T]) -> crate::Result {
+        self.validate_with_settings(s, &Settings::default())
+    }
+
+    fn validate_with_settings(&self, s: &[T], settings: &Settings) -> crate::Result {
+        check(&self.validators,
+              s.iter().enumerate().map(|(i, element)| (format!("[{}]", i), element)),
+              settings)
+    }
+}
+
+impl<T, V: Validators<T>> Validator<Vec<T>> for Each<V> {
+    type Output = crate::Result;
+
+    fn validate(&self, s: &Vec<T>) -> crate::Result {
+        Validator::<[T]>::validate(self, s)
+    }
+
+    fn validate_with_settings(&self, s: &Vec<T>, settings: &Settings) -> crate::Result {
+        Validator::<[T]>::validate_with_settings(self, s, settings)
+    }
+}
+
+/// Enforce that every value of a map passes all of `validators`.
+///
+/// Errors are tagged with the key of the value, so in the collection form of `rules!`
+/// they end up tagged like `labels["env"]`.
+pub const fn each_value<V>(validators: V) -> EachValue<V> {
+    EachValue { validators }
+}
+
+/// Validator built by `each_value`.
+#[derive(Debug, Clone)]
+pub struct EachValue<V> {
+    validators: V,
+}
+
+impl<K, T, S, V> Validator<HashMap<K, T, S>> for EachValue<V>
+    where K: Eq + Hash + Debug,
+          S: BuildHasher,
+          V: Validators<T>
+{
+    type Output = crate::Result;
+
+    fn validate(&self, m: &HashMap<K, T, S>) -> crate::Result {
+        self.validate_with_settings(m, &Settings::default())
+    }
+
+    fn validate_with_settings(&self, m: &HashMap<K, T, S>, settings: &Settings) -> crate::Result {
+        check(&self.validators, m.iter().map(|(key, value)| (format!("[{:?}]", key), value)), settings)
+    }
+}
+
+/// Enforce that every key of a map passes all of `validators`.
+///
+/// Errors are tagged with the offending key, so in the collection form of `rules!`
+/// they end up tagged like `labels["env"]`.
+pub const fn each_key<V>(validators: V) -> EachKey<V> {
+    EachKey { validators }
+}
+
+/// Validator built by `each_key`.
+#[derive(Debug, Clone)]
+pub struct EachKey<V> {
+    validators: V,
+}
+
+impl<K, T, S, V> Validator<HashMap<K, T, S>> for EachKey<V>
+    where K: Eq + Hash + Debug,
+          S: BuildHasher,
+          V: Validators<K>
+{
+    type Output = crate::Result;
+
+    fn validate(&self, m: &HashMap<K, T, S>) -> crate::Result {
+        self.validate_with_settings(m, &Settings::default())
+    }
+
+    fn validate_with_settings(&self, m: &HashMap<K, T, S>, settings: &Settings) -> crate::Result {
+        check(&self.validators, m.keys().map(|key| (format!("[{:?}]", key), key)), settings)
+    }
+}
+
+/// Runs `validators` on every element, tagged like `[3]`, until `settings` say to stop.
+fn check<'a, T: 'a, V, I>(validators: &V, elements: I, settings: &Settings) -> crate::Result
+    where V: Validators<T>,
+          I: Iterator<Item = (String, &'a T)>
+{
+    let mut collector = Collector::new(settings);
+    for (tag, element) in elements {
+        collector.begin_field();
+        if !collector.proceed() {
+            break;
+        }
+        validators.validate_into(element, &mut collector, &tag);
+    }
+    collector.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators::{contains, max};
+    use crate::FailFast;
+
+    fn tags(result: crate::Result) -> Vec<String> {
+        result.unwrap_err().0.into_iter().map(|m| m.tag).collect()
+    }
+
+    // each
+    #[test]
+    pub fn each_valid() {
+        let v = vec!["foo".to_owned(), "bar".to_owned()];
+        assert!(each(vec![max(3)]).validate(&v).is_ok());
+        assert!(each(vec![max(3)]).validate(&Vec::new()).is_ok());
+    }
+
+    #[test]
+    pub fn each_invalid() {
+        let v = vec!["foo".to_owned(), "barbaz".to_owned(), "quux".to_owned()];
+        assert_eq!(tags(each(vec![max(3)]).validate(&v)), vec!["[1]", "[2]"]);
+    }
+
+    #[test]
+    pub fn each_multiple_validators() {
+        let v = vec!["foo".to_owned(), "barbaz".to_owned()];
+        let result = each((max(3), contains("o"))).validate(&v).unwrap_err();
+        assert_eq!(result.0.len(), 1);
+        assert_eq!(result.0[0].tag, "[1]");
+        assert_eq!(result.0[0].invalids.len(), 2);
+    }
+
+    #[test]
+    pub fn each_with_settings() {
+        let v = vec!["foofoo".to_owned(), "barbar".to_owned(), "bazbaz".to_owned()];
+        let per_field = Settings { fail_fast: FailFast::PerField, max_errors: None };
+        let global = Settings { fail_fast: FailFast::Global, max_errors: None };
+        let limited = Settings { fail_fast: FailFast::Never, max_errors: Some(3) };
+        let validator = each((max(3), contains("x")));
+
+        let result = validator.validate_with_settings(&v, &per_field).unwrap_err();
+        assert_eq!(result.0.len(), 3);
+        assert_eq!(result.0[0].invalids.len(), 1);
+        assert_eq!(tags(validator.validate_with_settings(&v, &global)), vec!["[0]"]);
+        assert_eq!(tags(validator.validate_with_settings(&v, &limited)), vec!["[0]", "[1]"]);
+    }
+
+    // each_value
+    #[test]
+    pub fn each_value_valid() {
+        let mut m = HashMap::new();
+        m.insert("env", "prod".to_owned());
+        assert!(each_value(vec![max(4)]).validate(&m).is_ok());
+    }
+
+    #[test]
+    pub fn each_value_invalid() {
+        let mut m = HashMap::new();
+        m.insert("env", "production".to_owned());
+        m.insert("team", "core".to_owned());
+        assert_eq!(tags(each_value(vec![max(4)]).validate(&m)), vec!["[\"env\"]"]);
+    }
+
+    // each_key
+    #[test]
+    pub fn each_key_valid() {
+        let mut m = HashMap::new();
+        m.insert("env".to_owned(), 1);
+        assert!(each_key(vec![max(4)]).validate(&m).is_ok());
+    }
+
+    #[test]
+    pub fn each_key_invalid() {
+        let mut m = HashMap::new();
+        m.insert("env".to_owned(), 1);
+        m.insert("environment".to_owned(), 2);
+        assert_eq!(tags(each_key(vec![max(4)]).validate(&m)), vec!["[\"environment\"]"]);
+    }
+}
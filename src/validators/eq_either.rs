@@ -1,44 +1,73 @@
 use std::fmt::Display;
 
+use crate::{Arg, Validator, ValidatorResult};
 
 /// Enforce that `T` must equal `value`.
-pub fn eq<T: 'static>(value: T) -> Box<Fn(&T) -> ::ValidatorResult>
+pub const fn eq<T>(value: T) -> Equals<T>
     where T: PartialEq + Display
 {
-    Box::new(move |s: &T| {
-        if *s == value {
+    Equals { value }
+}
+
+/// Validator built by `eq`.
+#[derive(Debug, Clone)]
+pub struct Equals<T> {
+    value: T,
+}
+
+impl<T> Validator<T> for Equals<T>
+    where T: PartialEq + Display
+{
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &T) -> ValidatorResult {
+        if *s == self.value {
             Ok(())
         } else {
-            Err(::Invalid {
+            Err(crate::Invalid {
+                code: "accord.eq.not_equal".to_string(),
                 msg: "Does not equal %1.".to_string(),
-                args: vec![value.to_string()],
-				human_readable: format!("Does not equal '{}'", value)
+                args: vec![Arg::Str(self.value.to_string())],
+				human_readable: format!("Does not equal '{}'", self.value)
             })
         }
-    })
+    }
 }
 
 
 /// Enforce that `T` equals any of the values in `values`.
-pub fn either<T: 'static>(values: Vec<T>) -> Box<Fn(&T) -> ::ValidatorResult>
+pub const fn either<T>(values: Vec<T>) -> Either<T>
     where T: PartialEq + Display + Clone
 {
-    Box::new(move |s: &T| {
-        let x = values.iter().find(|x| *x == s);
+    Either { values }
+}
+
+/// Validator built by `either`.
+#[derive(Debug, Clone)]
+pub struct Either<T> {
+    values: Vec<T>,
+}
+
+impl<T> Validator<T> for Either<T>
+    where T: PartialEq + Display + Clone
+{
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &T) -> ValidatorResult {
+        let x = self.values.iter().find(|x| *x == s);
         let r = x.is_some();
-        if r == true {
+        if r {
             Ok(())
         } else {
-            let list = values.iter()
-                .cloned()
-                .fold(String::new(), |acc, v| format!("{}, {}", acc, v));
-            Err(::Invalid {
+            let list = Arg::List(self.values.iter().map(|v| Arg::Str(v.to_string())).collect());
+            Err(crate::Invalid {
+                code: "accord.either.not_allowed".to_string(),
                 msg: "Must be one of %1.".to_string(),
-                args: vec![list.to_string()],
-                human_readable: format!("Must be one of {}", list.to_string())
+                args: vec![list.clone()],
+                human_readable: format!("Must be one of {}", list)
             })
         }
-    })
+    }
 }
 
 #[cfg(test)]
@@ -60,46 +89,58 @@ mod tests {
     // eq
     #[test]
     pub fn eq_valid() {
-        assert!(eq(1)(&1).is_ok());
-        assert!(eq("yes")(&"yes").is_ok());
-        assert!(eq(TestEnum::Yes)(&TestEnum::Yes).is_ok());
-        assert!(eq(TestEnum::No)(&TestEnum::No).is_ok());
+        assert!(eq(1).validate(&1).is_ok());
+        assert!(eq("yes").validate(&"yes").is_ok());
+        assert!(eq(TestEnum::Yes).validate(&TestEnum::Yes).is_ok());
+        assert!(eq(TestEnum::No).validate(&TestEnum::No).is_ok());
     }
 
     #[test]
     pub fn eq_invalid() {
-        assert!(eq(1)(&2).is_err());
-        assert!(eq("yes")(&"yesn't").is_err());
-        assert!(eq(TestEnum::Yes)(&TestEnum::No).is_err());
-        assert!(eq(TestEnum::No)(&TestEnum::Yes).is_err());
+        assert!(eq(1).validate(&2).is_err());
+        assert!(eq("yes").validate(&"yesn't").is_err());
+        assert!(eq(TestEnum::Yes).validate(&TestEnum::No).is_err());
+        assert!(eq(TestEnum::No).validate(&TestEnum::Yes).is_err());
     
     }
 
     // either
     #[test]
     pub fn either_valid() {
-        assert!(either(vec![1, 2, 3])(&1).is_ok());
-        assert!(either(vec![1, 2, 3])(&2).is_ok());
-        assert!(either(vec![1, 2, 3])(&3).is_ok());
+        assert!(either(vec![1, 2, 3]).validate(&1).is_ok());
+        assert!(either(vec![1, 2, 3]).validate(&2).is_ok());
+        assert!(either(vec![1, 2, 3]).validate(&3).is_ok());
 
-        assert!(either(vec!["yes", "no"])(&"yes").is_ok());
-        assert!(either(vec!["yes", "no"])(&"no").is_ok());
+        assert!(either(vec!["yes", "no"]).validate(&"yes").is_ok());
+        assert!(either(vec!["yes", "no"]).validate(&"no").is_ok());
 
-        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow])(&TestEnum::Maybe).is_ok());
-        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow])(&TestEnum::IDontKnow).is_ok());
+        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow]).validate(&TestEnum::Maybe).is_ok());
+        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow]).validate(&TestEnum::IDontKnow).is_ok());
     }
 
     #[test]
     pub fn either_invalid() {
-        assert!(either(vec![1, 2, 3])(&4).is_err());
-        assert!(either(vec![1, 2, 3])(&5).is_err());
-        assert!(either(vec![1, 2, 3])(&6).is_err());
+        assert!(either(vec![1, 2, 3]).validate(&4).is_err());
+        assert!(either(vec![1, 2, 3]).validate(&5).is_err());
+        assert!(either(vec![1, 2, 3]).validate(&6).is_err());
+
+        assert!(either(vec!["yes", "no"]).validate(&"maybe").is_err());
+        assert!(either(vec!["yes", "no"]).validate(&"i don't know").is_err());
 
-        assert!(either(vec!["yes", "no"])(&"maybe").is_err());
-        assert!(either(vec!["yes", "no"])(&"i don't know").is_err());
+        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow]).validate(&TestEnum::Yes).is_err());
+        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow]).validate(&TestEnum::No).is_err());
+        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow]).validate(&TestEnum::RepeatTheQuestion).is_err());
+    }
 
-        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow])(&TestEnum::Yes).is_err());
-        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow])(&TestEnum::No).is_err());
-        assert!(either(vec![TestEnum::Maybe, TestEnum::IDontKnow])(&TestEnum::RepeatTheQuestion).is_err());
+    #[test]
+    pub fn args() {
+        assert_eq!(eq(1).validate(&2).unwrap_err().args, vec![Arg::Str("1".to_string())]);
+        assert_eq!(eq("007".to_string()).validate(&"7".to_string()).unwrap_err().args,
+                   vec![Arg::Str("007".to_string())]);
+        assert_eq!(either(vec!["01234", "1e3"]).validate(&"1234").unwrap_err().args,
+                   vec![Arg::List(vec![Arg::Str("01234".to_string()),
+                                       Arg::Str("1e3".to_string())])]);
+        assert_eq!(either(vec![TestEnum::Maybe]).validate(&TestEnum::No).unwrap_err().args,
+                   vec![Arg::List(vec![Arg::Str("Doesn't Matter".to_string())])]);
     }
 }
\ No newline at end of file
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+use crate::{Arg, Validator, ValidatorResult};
+
+/// Collections whose number of items can be validated by `min_items` and `max_items`.
+pub trait Items {
+    fn item_count(&self) -> usize;
+}
+
+impl<T> Items for [T] {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Items for Vec<T> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S: BuildHasher> Items for HashSet<T, S> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S: BuildHasher> Items for HashMap<K, V, S> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Items for BTreeSet<T> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> Items for BTreeMap<K, V> {
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Enforce that a collection contains maximum `max` items.
+pub const fn max_items(max: usize) -> MaxItems {
+    MaxItems { max }
+}
+
+/// Validator built by `max_items`.
+#[derive(Debug, Clone, Copy)]
+pub struct MaxItems {
+    max: usize,
+}
+
+impl<C: Items + ?Sized> Validator<C> for MaxItems {
+    type Output = ValidatorResult;
+
+    fn validate(&self, c: &C) -> ValidatorResult {
+        if c.item_count() <= self.max {
+            Ok(())
+        } else {
+            Err(crate::Invalid {
+                code: "accord.items.too_many".to_string(),
+                msg: "Must not contain more items than %1.".to_string(),
+                args: vec![Arg::named("max", &self.max)],
+                human_readable: format!("Must contain at most {} items", self.max)
+            })
+        }
+    }
+}
+
+/// Enforce that a collection contains minimum `min` items.
+pub const fn min_items(min: usize) -> MinItems {
+    MinItems { min }
+}
+
+/// Validator built by `min_items`.
+#[derive(Debug, Clone, Copy)]
+pub struct MinItems {
+    min: usize,
+}
+
+impl<C: Items + ?Sized> Validator<C> for MinItems {
+    type Output = ValidatorResult;
+
+    fn validate(&self, c: &C) -> ValidatorResult {
+        if c.item_count() >= self.min {
+            Ok(())
+        } else {
+            Err(crate::Invalid {
+                code: "accord.items.too_few".to_string(),
+                msg: "Must not contain less items than %1.".to_string(),
+                args: vec![Arg::named("min", &self.min)],
+                human_readable: format!("Must contain at least {} items", self.min)
+            })
+        }
+    }
+}
+
+/// Enforce that no item appears more than once in a sequence.
+///
+/// The index of the first duplicate is given as the argument of the error.
+pub const fn unique_items() -> UniqueItems {
+    UniqueItems
+}
+
+/// Validator built by `unique_items`.
+#[derive(Debug, Clone, Copy)]
+pub struct UniqueItems;
+
+impl<T: Eq + Hash> Validator<[T]> for UniqueItems {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &[T]) -> ValidatorResult {
+        let mut seen = HashSet::with_capacity(s.len());
+        for (i, item) in s.iter().enumerate() {
+            if !seen.insert(item) {
+                return Err(crate::Invalid {
+                    code: "accord.items.duplicate".to_string(),
+                    msg: "Must not contain duplicates, found one at %1.".to_string(),
+                    args: vec![Arg::named("index", &i)],
+                    human_readable: format!("Must not contain duplicates, item {} is a duplicate", i)
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Eq + Hash> Validator<Vec<T>> for UniqueItems {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &Vec<T>) -> ValidatorResult {
+        Validator::<[T]>::validate(self, s)
+    }
+}
+
+impl<T: Eq + Hash, const N: usize> Validator<[T; N]> for UniqueItems {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &[T; N]) -> ValidatorResult {
+        Validator::<[T]>::validate(self, s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // max_items
+    #[test]
+    pub fn max_items_valid() {
+        assert!(max_items(3).validate(&vec![1, 2, 3]).is_ok());
+        assert!(max_items(3).validate(&vec![1]).is_ok());
+        assert!(max_items(3).validate(&Vec::<i32>::new()).is_ok());
+        assert!(max_items(1).validate(&[1].iter().cloned().collect::<HashSet<_>>()).is_ok());
+        assert!(max_items(1).validate(&[(1, 2)].iter().cloned().collect::<HashMap<_, _>>()).is_ok());
+    }
+
+    #[test]
+    pub fn max_items_invalid() {
+        assert!(max_items(3).validate(&vec![1, 2, 3, 4]).is_err());
+        assert!(max_items(1).validate(&[1, 2].iter().cloned().collect::<HashSet<_>>()).is_err());
+        assert!(max_items(1).validate(&[(1, 2), (3, 4)].iter().cloned().collect::<HashMap<_, _>>()).is_err());
+    }
+
+    // min_items
+    #[test]
+    pub fn min_items_valid() {
+        assert!(min_items(2).validate(&vec![1, 2]).is_ok());
+        assert!(min_items(2).validate(&vec![1, 2, 3]).is_ok());
+        assert!(min_items(2).validate(&[1, 2].iter().cloned().collect::<HashSet<_>>()).is_ok());
+        assert!(min_items(1).validate(&[(1, 2)].iter().cloned().collect::<HashMap<_, _>>()).is_ok());
+    }
+
+    #[test]
+    pub fn min_items_invalid() {
+        assert!(min_items(2).validate(&vec![1]).is_err());
+        assert!(min_items(2).validate(&Vec::<i32>::new()).is_err());
+        assert!(min_items(2).validate(&[1].iter().cloned().collect::<HashSet<_>>()).is_err());
+        assert!(min_items(1).validate(&HashMap::<i32, i32>::new()).is_err());
+    }
+
+    // unique_items
+    #[test]
+    pub fn unique_items_valid() {
+        assert!(unique_items().validate(&[1, 2, 3]).is_ok());
+        assert!(unique_items().validate(&["a", "b"]).is_ok());
+        assert!(Validator::<Vec<i32>>::validate(&unique_items(), &Vec::new()).is_ok());
+    }
+
+    #[test]
+    pub fn unique_items_invalid() {
+        assert!(unique_items().validate(&[1, 1]).is_err());
+        assert_eq!(unique_items().validate(&["a", "b", "c", "b", "a"]).unwrap_err().string_args(),
+                   vec!["3".to_string()]);
+    }
+}
@@ -1,47 +1,103 @@
-use ValidatorResult;
+use crate::{Arg, Validator, ValidatorResult};
 
 #[cfg(feature = "inclusive_range")]
 use std::ops::RangeInclusive;
 
 /// Enforce that a `String` is maximum `max` characters long.
-pub fn max(max: usize) -> Box<Fn(&String) -> ::ValidatorResult> {
-    Box::new(move |s: &String| {
-        if s.len() <= max {
+pub const fn max(max: usize) -> Max {
+    Max { max }
+}
+
+/// Validator built by `max`.
+#[derive(Debug, Clone, Copy)]
+pub struct Max {
+    max: usize,
+}
+
+impl Validator<String> for Max {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        if s.len() <= self.max {
             Ok(())
         } else {
-            Err(::Invalid {
+            Err(crate::Invalid {
+                code: "accord.length.too_long".to_string(),
                 msg: "Must not contain more characters than %1.".to_string(),
-                args: vec![max.to_string()],
-                human_readable: format!("Must contain less than {} characters", max)
+                args: vec![Arg::named("max", &self.max)],
+                human_readable: format!("Must contain less than {} characters", self.max)
             })
         }
-    })
+    }
 }
 
 // Enforce that a `String` is minimum `min` characters long.
-pub fn min(min: usize) -> Box<Fn(&String) -> ValidatorResult> {
-    Box::new(move |s: &String| {
-        if s.len() >= min {
+pub const fn min(min: usize) -> Min {
+    Min { min }
+}
+
+/// Validator built by `min`.
+#[derive(Debug, Clone, Copy)]
+pub struct Min {
+    min: usize,
+}
+
+impl Validator<String> for Min {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        if s.len() >= self.min {
             Ok(())
         } else {
-            Err(::Invalid {
+            Err(crate::Invalid {
+                code: "accord.length.too_short".to_string(),
                 msg: "Must contain more than %1 characters".to_string(),
-                args: vec![min.to_string()],
-                human_readable: format!("Must contain more than {} characters", min)
+                args: vec![Arg::named("min", &self.min)],
+                human_readable: format!("Must contain more than {} characters", self.min)
             })
         }
-    })
+    }
+}
+
+/// Validator built by `length`.
+#[derive(Debug, Clone, Copy)]
+pub struct Length {
+    min: usize,
+    max: usize,
+}
+
+/// Validator built by `length_if_present`.
+#[derive(Debug, Clone, Copy)]
+pub struct LengthIfPresent {
+    length: Length,
+}
+
+impl Validator<Option<String>> for LengthIfPresent {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &Option<String>) -> ValidatorResult {
+        s.as_ref().map_or(Ok(()), |s| self.length.validate(s))
+    }
 }
 
 #[cfg(not(feature = "inclusive_range"))]
 /// Enforce that a string is minimum `mi` and maximum `ma` characters long.
-pub fn length(mi: usize, ma: usize) -> Box<Fn(&String) -> ::ValidatorResult> {
-    Box::new(move |s: &String| {
-        match (min(mi)(s), max(ma)(s)) {
+pub const fn length(mi: usize, ma: usize) -> Length {
+    Length { min: mi, max: ma }
+}
+
+#[cfg(not(feature = "inclusive_range"))]
+impl Validator<String> for Length {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        let (mi, ma) = (self.min, self.max);
+        match (min(mi).validate(s), max(ma).validate(s)) {
             (Err(_), Err(_)) => {
-                Err(::Invalid {
+                Err(crate::Invalid {
+                    code: "accord.length.out_of_range".to_string(),
                     msg: "Must not be less characters than %1 and not more than %2.".to_string(),
-                    args: vec![mi.to_string(), ma.to_string()],
+                    args: vec![Arg::named("min", &mi), Arg::named("max", &ma)],
                     human_readable: format!("Must contain between {} and {} characters", mi, ma - 1)
                 })
             }
@@ -49,89 +105,63 @@ pub fn length(mi: usize, ma: usize) -> Box<Fn(&String) -> ::ValidatorResult> {
             (_, Err(e)) => Err(e),
             (_, _) => Ok(()),
         }
-    })
+    }
 }
 
 #[cfg(not(feature = "inclusive_range"))]
 /// Enforce that a string is minimum `mi` and maximum `ma` characters long if it is present. Always ok if not present.
-pub fn length_if_present(mi: usize, ma: usize) -> Box<Fn(&Option<String>) -> ::ValidatorResult> {
-    Box::new(move |s: &Option<String>| {
-        if s.is_none() {
-            return Ok(());
-        }
-        let s = s.as_ref().unwrap();
-        match (min(mi)(s), max(ma)(s)) {
-            (Err(_), Err(_)) => {
-                Err(::Invalid {
-                    msg: "Must not be less characters than %1 and not more than %2.".to_string(),
-                    args: vec![mi.to_string(), ma.to_string()],
-                    human_readable: format!("Must contain between {} and {} characters", mi, ma - 1)
-                })
-            }
-            (Err(e), _) => Err(e),
-            (_, Err(e)) => Err(e),
-            (_, _) => Ok(()),
-        }
-    })
+///
+/// This is a shorthand for `optional(length(mi, ma))` that returns a plain `ValidatorResult`.
+#[deprecated(note = "use `optional(length(mi, ma))` instead")]
+pub const fn length_if_present(mi: usize, ma: usize) -> LengthIfPresent {
+    LengthIfPresent { length: length(mi, ma) }
 }
 
 // TODO: this could be better rust-lang/rust#48111 & rust-lang/rust#32311
 
 #[cfg(feature = "inclusive_range")]
 /// Enforce that a string is minimum `mi` and maximum `ma` characters long.
-pub fn length(range: RangeInclusive<usize>) -> Box<Fn(&String) -> ::ValidatorResult> {
+pub fn length(range: RangeInclusive<usize>) -> Length {
     // do bounds checking here so we can panic early if needed
     if range.end() <= range.start() {
         panic!("Invalid range!"); // TODO: Bad way to do this.
     }
 
-    Box::new(move |s: &String| {
-        let start = range.start();
-        let end = range.end();
-        match (min(*start)(s), max(*end)(s)) {
+    let (min, max) = range.into_inner();
+    Length { min, max }
+}
+
+#[cfg(feature = "inclusive_range")]
+impl Validator<String> for Length {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        let start = self.min;
+        let end = self.max;
+        match (min(start).validate(s), max(end).validate(s)) {
             (Ok(_), Ok(_)) => Ok(()),
             _ => {
-                Err(::Invalid {
+                Err(crate::Invalid {
+                    code: "accord.length.out_of_range".to_string(),
                     msg: "Must not be less characters than %1 and not more than %2."
                         .to_string(),
-                    args: vec![start.to_string(), end.to_string()],
+                    args: vec![Arg::named("min", &start), Arg::named("max", &end)],
                     human_readable: format!("Must contain between {} and {} characters", start, end)
                 })
             }
         }
-    })
+    }
 }
 
 // TODO: this could be better rust-lang/rust#48111 & rust-lang/rust#32311
 
 #[cfg(feature = "inclusive_range")]
 /// Enforce that a string is minimum `mi` and maximum `ma` characters long if it is present. Always ok if not present.
-pub fn length_if_present(range: RangeInclusive<usize>) -> Box<Fn(&Option<String>) -> ::ValidatorResult> {
-    // do bounds checking here so we can panic early if needed
-    if range.end() <= range.start() {
-        panic!("Invalid range!"); 
-    }
-
-    Box::new(move |s: &Option<String>| {
-        if s.is_none() {
-            return Ok(());
-        }
-        let s = s.as_ref().unwrap();
-        
-        let start = range.start();
-        let end = range.end();
-        match (min(*start)(s), max(*end)(s)) {
-            (Ok(_), Ok(_)) => Ok(()),
-            _ => {
-                Err(::Invalid {
-                    msg: "Must not be less characters than %1 and not more than %2."
-                        .to_string(),
-                    args: vec![start.to_string(), end.to_string()],
-                    human_readable: format!("Must contain between {} and {} characters", start, end)
-                })
-            }
-        }
-    })
+///
+/// This is a shorthand for `optional(length(range))` that returns a plain `ValidatorResult`.
+#[deprecated(note = "use `optional(length(range))` instead")]
+pub fn length_if_present(range: RangeInclusive<usize>) -> LengthIfPresent {
+    LengthIfPresent { length: length(range) }
 }
 
 #[cfg(test)]
@@ -141,98 +171,110 @@ mod tests {
     // max
     #[test]
     pub fn max_valid() {
-        assert!(max(3)(&"123".to_owned()).is_ok());
-        assert!(max(3)(&"12".to_owned()).is_ok());
-        assert!(max(3)(&"1".to_owned()).is_ok());
+        assert!(max(3).validate(&"123".to_owned()).is_ok());
+        assert!(max(3).validate(&"12".to_owned()).is_ok());
+        assert!(max(3).validate(&"1".to_owned()).is_ok());
     }
 
     #[test]
     pub fn max_invalid() {
-        assert!(max(3)(&"1234".to_owned()).is_err());
-        assert!(max(3)(&"12345".to_owned()).is_err());
-        assert!(max(3)(&"123456".to_owned()).is_err());
+        assert!(max(3).validate(&"1234".to_owned()).is_err());
+        assert!(max(3).validate(&"12345".to_owned()).is_err());
+        assert!(max(3).validate(&"123456".to_owned()).is_err());
     }
 
     // min
     #[test]
     pub fn min_valid() {
-        assert!(min(3)(&"123".to_owned()).is_ok());
-        assert!(min(3)(&"1234".to_owned()).is_ok());
-        assert!(min(3)(&"12345".to_owned()).is_ok());
+        assert!(min(3).validate(&"123".to_owned()).is_ok());
+        assert!(min(3).validate(&"1234".to_owned()).is_ok());
+        assert!(min(3).validate(&"12345".to_owned()).is_ok());
     }
 
     #[test]
     pub fn min_invalid() {
-        assert!(min(3)(&"12".to_owned()).is_err());
-        assert!(min(3)(&"1".to_owned()).is_err());
-        assert!(min(3)(&"".to_owned()).is_err());
+        assert!(min(3).validate(&"12".to_owned()).is_err());
+        assert!(min(3).validate(&"1".to_owned()).is_err());
+        assert!(min(3).validate(&"".to_owned()).is_err());
+    }
+
+    #[test]
+    pub fn min_max_codes() {
+        assert_eq!(min(3).validate(&"12".to_owned()).unwrap_err().code, "accord.length.too_short");
+        assert_eq!(max(3).validate(&"1234".to_owned()).unwrap_err().code, "accord.length.too_long");
     }
 
     // length & length_if_present
 
     #[cfg(not(feature = "inclusive_range"))]
     mod length {
+        #![allow(deprecated)]
+
         use super::super::{length, length_if_present};
+        use crate::Validator;
 
         // length
         #[test]
         pub fn length_valid() {
-            assert!(length(1, 3)(&"123".to_owned()).is_ok());
-            assert!(length(1, 3)(&"12".to_owned()).is_ok());
-            assert!(length(1, 3)(&"1".to_owned()).is_ok());
+            assert!(length(1, 3).validate(&"123".to_owned()).is_ok());
+            assert!(length(1, 3).validate(&"12".to_owned()).is_ok());
+            assert!(length(1, 3).validate(&"1".to_owned()).is_ok());
         }
 
         #[test]
         pub fn length_invalid() {
-            assert!(length(1, 3)(&"1234".to_owned()).is_err());
-            assert!(length(1, 3)(&"".to_owned()).is_err());
+            assert!(length(1, 3).validate(&"1234".to_owned()).is_err());
+            assert!(length(1, 3).validate(&"".to_owned()).is_err());
         }
         
         // length_if_present
         #[test]
         pub fn length_if_present_valid() {
-            assert!(length_if_present(1, 3)(&Some("123".to_owned())).is_ok());
-            assert!(length_if_present(1, 3)(&Some("12".to_owned())).is_ok());
-            assert!(length_if_present(1, 3)(&None).is_ok());
+            assert!(length_if_present(1, 3).validate(&Some("123".to_owned())).is_ok());
+            assert!(length_if_present(1, 3).validate(&Some("12".to_owned())).is_ok());
+            assert!(length_if_present(1, 3).validate(&None).is_ok());
         }
 
         #[test]
         pub fn length_if_present_invalid() {
-            assert!(length_if_present(1, 3)(&Some("1234".to_owned())).is_err());
-            assert!(length_if_present(1, 3)(&Some("".to_owned())).is_err());
+            assert!(length_if_present(1, 3).validate(&Some("1234".to_owned())).is_err());
+            assert!(length_if_present(1, 3).validate(&Some("".to_owned())).is_err());
         }
     }
 
     #[cfg(feature = "inclusive_range")]
     mod length {
+        #![allow(deprecated)]
+
         use super::super::{length, length_if_present};
+        use crate::Validator;
 
         // length
         #[test]
         pub fn length_valid() {
-            assert!(length(1..=3)(&"123".to_owned()).is_ok());
-            assert!(length(1..=3)(&"12".to_owned()).is_ok());
-            assert!(length(1..=3)(&"1".to_owned()).is_ok());
+            assert!(length(1..=3).validate(&"123".to_owned()).is_ok());
+            assert!(length(1..=3).validate(&"12".to_owned()).is_ok());
+            assert!(length(1..=3).validate(&"1".to_owned()).is_ok());
         }
 
         #[test]
         pub fn length_invalid() {
-            assert!(length(1..=3)(&"1234".to_owned()).is_err());
-            assert!(length(1..=3)(&"".to_owned()).is_err());
+            assert!(length(1..=3).validate(&"1234".to_owned()).is_err());
+            assert!(length(1..=3).validate(&"".to_owned()).is_err());
         }
         
         // length_if_present
         #[test]
         pub fn length_if_present_valid() {
-            assert!(length_if_present(1..=3)(&Some("123".to_owned())).is_ok());
-            assert!(length_if_present(1..=3)(&Some("12".to_owned())).is_ok());
-            assert!(length_if_present(1..=3)(&None).is_ok());
+            assert!(length_if_present(1..=3).validate(&Some("123".to_owned())).is_ok());
+            assert!(length_if_present(1..=3).validate(&Some("12".to_owned())).is_ok());
+            assert!(length_if_present(1..=3).validate(&None).is_ok());
         }
 
         #[test]
         pub fn length_if_present_invalid() {
-            assert!(length_if_present(1..=3)(&Some("1234".to_owned())).is_err());
-            assert!(length_if_present(1..=3)(&Some("".to_owned())).is_err());
+            assert!(length_if_present(1..=3).validate(&Some("1234".to_owned())).is_err());
+            assert!(length_if_present(1..=3).validate(&Some("".to_owned())).is_err());
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+
+use crate::catalog::substitute;
+use crate::{Validator, ValidatorResult};
+
+/// Replace the message of the error `validator` produces with `msg`, keeping its code and args.
+///
+/// Placeholders like `%1` in `msg` are filled in with the args for the `human_readable`.
+///
+/// Since the code is kept, a `Catalog` with a translation for the code renders that translation
+/// rather than `msg`. Use `with_msg` with a code of its own for copy that is translated separately.
+pub fn message<V, M>(validator: V, msg: M) -> WithMsg<V>
+    where M: Into<Cow<'static, str>>
+{
+    let msg = msg.into();
+    WithMsg {
+        validator,
+        code: None,
+        human_readable: msg.clone(),
+        msg,
+    }
+}
+
+/// Replace the code, message and human readable text of the error `validator` produces,
+/// keeping its args.
+///
+/// Placeholders like `%1` in `human_readable` are filled in with the args.
+pub fn with_msg<V, C, M, H>(validator: V, code: C, msg: M, human_readable: H) -> WithMsg<V>
+    where C: Into<Cow<'static, str>>,
+          M: Into<Cow<'static, str>>,
+          H: Into<Cow<'static, str>>
+{
+    WithMsg {
+        validator,
+        code: Some(code.into()),
+        msg: msg.into(),
+        human_readable: human_readable.into(),
+    }
+}
+
+/// Validator built by `message` and `with_msg`.
+#[derive(Debug, Clone)]
+pub struct WithMsg<V> {
+    validator: V,
+    code: Option<Cow<'static, str>>,
+    msg: Cow<'static, str>,
+    human_readable: Cow<'static, str>,
+}
+
+impl<T: ?Sized, V: Validator<T, Output = ValidatorResult>> Validator<T> for WithMsg<V> {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &T) -> ValidatorResult {
+        self.validator.validate(s).map_err(|mut invalid| {
+            if let Some(ref code) = self.code {
+                invalid.code = code.to_string();
+            }
+            invalid.msg = self.msg.to_string();
+            invalid.human_readable = substitute(&self.human_readable, &invalid.args);
+            invalid
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators::{contains, max, range};
+
+    // message
+    #[test]
+    pub fn message_valid() {
+        assert!(message(max(3), "Too long").validate(&"123".to_owned()).is_ok());
+    }
+
+    #[test]
+    pub fn message_invalid() {
+        let invalid = message(max(3), "At most %1, please.").validate(&"1234".to_owned()).unwrap_err();
+        assert_eq!(invalid.code, "accord.length.too_long");
+        assert_eq!(invalid.msg, "At most %1, please.");
+        assert_eq!(invalid.string_args(), vec!["3".to_string()]);
+        assert_eq!(invalid.human_readable, "At most 3, please.");
+    }
+
+    // with_msg
+    #[test]
+    pub fn with_msg_valid() {
+        assert!(with_msg(contains("@"), "email.missing_at", "Need an @.", "Need an @")
+            .validate(&"a@b".to_owned())
+            .is_ok());
+    }
+
+    #[test]
+    pub fn with_msg_invalid() {
+        #[cfg(not(feature = "inclusive_range"))]
+        let workers = range(1, 64);
+        #[cfg(feature = "inclusive_range")]
+        let workers = range(1..=64);
+        let validator = with_msg(workers, "workers.out_of_range",
+                                 "Wrong number of workers.".to_string(),
+                                 "Workers must be between %1 and %2");
+        let invalid = validator.validate(&0).unwrap_err();
+        assert_eq!(invalid.code, "workers.out_of_range");
+        assert_eq!(invalid.msg, "Wrong number of workers.");
+        assert_eq!(invalid.human_readable, "Workers must be between 1 and 64");
+    }
+}
@@ -1,16 +1,30 @@
 // validators are in other files for convenience
 
+mod combinators;
+mod conditional;
 mod contains;
+mod each;
 mod eq_either;
+mod items;
 mod length;
+mod message;
+mod nested;
+mod optional;
 mod range;
 
 #[cfg(feature = "regex_validator")]
 mod regex;
 
+pub use self::combinators::*;
+pub use self::conditional::*;
 pub use self::contains::*;
+pub use self::each::*;
 pub use self::eq_either::*;
+pub use self::items::*;
 pub use self::length::*;
+pub use self::message::*;
+pub use self::nested::*;
+pub use self::optional::*;
 pub use self::range::*;
 
 #[cfg(feature = "regex_validator")]
@@ -0,0 +1,111 @@
+use crate::{Accord, AccordWith, Settings, Validator};
+
+/// Enforce that a value implementing `Accord` is valid itself.
+///
+/// Use this in the collection form of `rules!`; the tags of the errors found inside the value
+/// are prefixed with the tag given to it, e.g. `address.street`. With settings, the value is
+/// validated with `Accord::validate_with_settings`.
+pub const fn nested() -> Nested {
+    Nested
+}
+
+/// Validator built by `nested`.
+#[derive(Debug, Clone, Copy)]
+pub struct Nested;
+
+impl<T: Accord + ?Sized> Validator<T> for Nested {
+    type Output = crate::Result;
+
+    fn validate(&self, value: &T) -> crate::Result {
+        value.validate()
+    }
+
+    fn validate_with_settings(&self, value: &T, settings: &Settings) -> crate::Result {
+        value.validate_with_settings(settings)
+    }
+}
+
+/// Enforce that a value implementing `AccordWith` is valid itself, given `ctx`.
+///
+/// This is `nested` for data that needs the same context as the data containing it.
+pub const fn nested_with<C: ?Sized>(ctx: &C) -> NestedWith<'_, C> {
+    NestedWith { ctx }
+}
+
+/// Validator built by `nested_with`.
+#[derive(Debug)]
+pub struct NestedWith<'a, C: ?Sized> {
+    ctx: &'a C,
+}
+
+impl<'a, C: ?Sized, T: AccordWith<C> + ?Sized> Validator<T> for NestedWith<'a, C> {
+    type Output = crate::Result;
+
+    fn validate(&self, value: &T) -> crate::Result {
+        value.validate_with(self.ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Invalid, MultipleError, MultipleInvalid};
+
+    struct Valid;
+
+    impl Accord for Valid {
+        fn validate(&self) -> crate::Result {
+            Ok(())
+        }
+    }
+
+    struct Broken;
+
+    impl Accord for Broken {
+        fn validate(&self) -> crate::Result {
+            Err(MultipleError(vec![MultipleInvalid {
+                tag: "street".to_string(),
+                invalids: vec![Invalid {
+                    code: "test.broken".to_string(),
+                    msg: "Broken.".to_string(),
+                    args: vec![],
+                    human_readable: "Broken".to_string(),
+                }],
+            }]))
+        }
+    }
+
+    struct Limited(usize);
+
+    impl AccordWith<usize> for Limited {
+        fn validate_with(&self, limit: &usize) -> crate::Result {
+            if self.0 <= *limit {
+                Ok(())
+            } else {
+                Broken.validate()
+            }
+        }
+    }
+
+    // nested
+    #[test]
+    pub fn nested_valid() {
+        assert!(nested().validate(&Valid).is_ok());
+    }
+
+    #[test]
+    pub fn nested_invalid() {
+        assert!(nested().validate(&Broken).is_err());
+    }
+
+    // nested_with
+    #[test]
+    pub fn nested_with_valid() {
+        assert!(nested_with(&3).validate(&Limited(3)).is_ok());
+    }
+
+    #[test]
+    pub fn nested_with_invalid() {
+        assert!(nested_with(&3).validate(&Limited(4)).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{Settings, Validator};
+
+/// Run `validator` on the value if it is present. Always ok if not present.
+///
+/// This works with any validator, e.g. `optional(contains("@"))` or `optional(range(1, 10))`.
+pub const fn optional<V>(validator: V) -> Optional<V> {
+    Optional { validator }
+}
+
+/// Validator built by `optional`.
+#[derive(Debug, Clone)]
+pub struct Optional<V> {
+    validator: V,
+}
+
+impl<T, V: Validator<T>> Validator<Option<T>> for Optional<V> {
+    type Output = Option<V::Output>;
+
+    fn validate(&self, s: &Option<T>) -> Option<V::Output> {
+        s.as_ref().map(|s| self.validator.validate(s))
+    }
+
+    fn validate_with_settings(&self, s: &Option<T>, settings: &Settings) -> Option<V::Output> {
+        s.as_ref().map(|s| self.validator.validate_with_settings(s, settings))
+    }
+}
+
+/// Enforce that an optional value is present.
+pub const fn required() -> Required {
+    Required
+}
+
+/// Validator built by `required`.
+#[derive(Debug, Clone, Copy)]
+pub struct Required;
+
+impl<T> Validator<Option<T>> for Required {
+    type Output = crate::ValidatorResult;
+
+    fn validate(&self, s: &Option<T>) -> crate::ValidatorResult {
+        if s.is_some() {
+            Ok(())
+        } else {
+            Err(crate::Invalid {
+                code: "accord.required.missing".to_string(),
+                msg: "Must be present.".to_string(),
+                args: vec![],
+                human_readable: "Must be present".to_string()
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validators::{contains, eq};
+
+    // optional
+    #[test]
+    pub fn optional_valid() {
+        assert!(optional(contains("@")).validate(&Some("a@b".to_owned())).unwrap().is_ok());
+        assert!(optional(contains("@")).validate(&None).is_none());
+        assert!(optional(eq(1)).validate(&Some(1)).unwrap().is_ok());
+    }
+
+    #[test]
+    pub fn optional_invalid() {
+        assert!(optional(contains("@")).validate(&Some("ab".to_owned())).unwrap().is_err());
+        assert!(optional(eq(1)).validate(&Some(2)).unwrap().is_err());
+    }
+
+    // required
+    #[test]
+    pub fn required_valid() {
+        assert!(required().validate(&Some(1)).is_ok());
+        assert!(required().validate(&Some("".to_owned())).is_ok());
+    }
+
+    #[test]
+    pub fn required_invalid() {
+        assert!(required().validate(&None::<i32>).is_err());
+    }
+}
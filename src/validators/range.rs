@@ -3,45 +3,46 @@ use std::fmt::Display;
 #[cfg(feature = "inclusive_range")]
 use std::ops::RangeInclusive;
 
-#[cfg(not(feature = "inclusive_range"))]
-pub fn range<T: 'static + PartialOrd + Display + Clone>(a: T,
-                                                        b: T)
-                                                        -> Box<Fn(&T) -> ::ValidatorResult> {
-    Box::new(move |s: &T| {
-        if *s >= a && *s <= b {
+use crate::{Arg, ToArg, Validator, ValidatorResult};
+
+/// Validator built by `range`.
+#[derive(Debug, Clone)]
+pub struct Range<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: PartialOrd + Display + ToArg> Validator<T> for Range<T> {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &T) -> ValidatorResult {
+        if *s >= self.start && *s <= self.end {
             Ok(())
         } else {
-            Err(::Invalid {
+            Err(crate::Invalid {
+                code: "accord.range.out_of_range".to_string(),
                 msg: "Must be in the range %1..%2.".to_string(),
-                args: vec![a.to_string(), b.to_string()],
-				human_readable: format!("Must be between {} and {}", a, b)
+                args: vec![Arg::named("min", &self.start), Arg::named("max", &self.end)],
+				human_readable: format!("Must be between {} and {}", self.start, self.end)
             })
         }
-    })
+    }
+}
+
+#[cfg(not(feature = "inclusive_range"))]
+pub const fn range<T: PartialOrd + Display + Clone + ToArg>(a: T, b: T) -> Range<T> {
+    Range { start: a, end: b }
 }
 
 #[cfg(feature = "inclusive_range")]
-pub fn range<T: 'static + PartialOrd + Display + Clone>(range: RangeInclusive<T>)
-                                                        -> Box<Fn(&T) -> ::ValidatorResult> {
+pub fn range<T: PartialOrd + Display + Clone + ToArg>(range: RangeInclusive<T>) -> Range<T> {
     // do bounds checking here so we can panic early if needed
     if range.end() <= range.start() {
         panic!("Invalid range!"); // TODO: Bad way to do this.
     }
 
-    Box::new(move |s: &T| {
-        let start = range.start();
-        let end = range.end();
-
-        if *s >= *start && *s <= *end {
-            Ok(())
-        } else {
-            Err(::Invalid {
-                msg: "Must be in the range %1..%2.".to_string(),
-                args: vec![start.to_string(), end.to_string()],
-				human_readable: format!("Must be between {} and {}", start, end)
-            })
-        }
-    })
+    let (start, end) = range.into_inner();
+    Range { start, end }
 }
 
 #[cfg(test)]
@@ -53,15 +54,15 @@ mod tests {
 
     #[test]
     pub fn range_valid() {
-        assert!(range(1, 100)(&1).is_ok());
-        assert!(range(1, 100)(&50).is_ok());
-        assert!(range(1, 100)(&100).is_ok());
+        assert!(range(1, 100).validate(&1).is_ok());
+        assert!(range(1, 100).validate(&50).is_ok());
+        assert!(range(1, 100).validate(&100).is_ok());
     }
 
     #[test]
     pub fn range_invalid() {
-        assert!(range(1, 100)(&0).is_err());
-        assert!(range(1, 100)(&101).is_err());
+        assert!(range(1, 100).validate(&0).is_err());
+        assert!(range(1, 100).validate(&101).is_err());
     }
 }
 
@@ -74,14 +75,14 @@ mod tests {
 
     #[test]
     pub fn range_valid() {
-        assert!(range(1..=100)(&1).is_ok());
-        assert!(range(1..=100)(&50).is_ok());
-        assert!(range(1..=100)(&100).is_ok());
+        assert!(range(1..=100).validate(&1).is_ok());
+        assert!(range(1..=100).validate(&50).is_ok());
+        assert!(range(1..=100).validate(&100).is_ok());
     }
 
     #[test]
     pub fn range_invalid() {
-        assert!(range(1..=100)(&0).is_err());
-        assert!(range(1..=100)(&101).is_err());
+        assert!(range(1..=100).validate(&0).is_err());
+        assert!(range(1..=100).validate(&101).is_err());
     }
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use regex::RegexBuilder;
 
+use crate::{ToArg, Validator, ValidatorResult};
+
 /// Enforce that a string must match a given regex
 /// Flags is a string containing all the flags that affect the regex.
 /// i = ignore case
@@ -12,7 +14,14 @@ use regex::RegexBuilder;
 /// r"a..." will match "a12345678" as a match is present.
 /// To force the value to match the regex exactly, use ^ and $
 /// e.g: r"^a...$" will not match "a12345678"
-pub fn regex(regex: &'static str, flags: &'static str) -> Box<Fn(&String) -> ::ValidatorResult> {
+///
+/// The regex is compiled when the validator is built, so build it once, e.g. in a
+/// `lazy_static`, if it is used often.
+pub fn regex(regex: &'static str, flags: &'static str) -> Regex {
+    regex_with_code(regex, flags, "accord.regex.no_match")
+}
+
+fn regex_with_code(regex: &'static str, flags: &'static str, code: &'static str) -> Regex {
     let regex = RegexBuilder::new(regex)
         .case_insensitive(flags.contains("i"))
         .multi_line(flags.contains("m"))
@@ -22,28 +31,43 @@ pub fn regex(regex: &'static str, flags: &'static str) -> Box<Fn(&String) -> ::V
         .ignore_whitespace(flags.contains("x"))
         .build()
         .expect("Invalid regex in validator!");
-    Box::new(move |s: &String| {
-        if regex.is_match(s) {
+    Regex { regex, flags, code }
+}
+
+/// Validator built by `regex`.
+#[derive(Debug, Clone)]
+pub struct Regex {
+    regex: ::regex::Regex,
+    flags: &'static str,
+    code: &'static str,
+}
+
+impl Validator<String> for Regex {
+    type Output = ValidatorResult;
+
+    fn validate(&self, s: &String) -> ValidatorResult {
+        if self.regex.is_match(s) {
             Ok(())
         } else {
-            Err(::Invalid {
+            Err(crate::Invalid {
+                code: self.code.to_string(),
                 msg: "Must match regex '/%1/%2'.".to_string(),
                 // TODO: sucks to clone this, it could probably be a &'static str
-                args: vec![regex.as_str().to_owned(), flags.to_owned()],
-				human_readable: format!("Must match regex '/{}/{}'", regex.as_str().clone(), flags)
+                args: vec![self.regex.as_str().to_arg(), self.flags.to_arg()],
+				human_readable: format!("Must match regex '/{}/{}'", self.regex.as_str(), self.flags)
             })
         }
-    })
+    }
 }
 
 /// Convenience function for validating email addresses
-pub fn email() -> Box<Fn(&String) -> ::ValidatorResult> {
-    regex(r"^[A-Z0-9._%+-]+@[A-Z0-9.-]+\.[A-Z]{2,}$", "i")
+pub fn email() -> Regex {
+    regex_with_code(r"^[A-Z0-9._%+-]+@[A-Z0-9.-]+\.[A-Z]{2,}$", "i", "accord.email.invalid")
 }
 
 /// Convenience function for validating URLs.
-pub fn url() -> Box<Fn(&String) -> ::ValidatorResult> {
-    regex(r"^(https?://)?([A-Z]+\.)+[A-Z]{2,}(/[A-Z%0-9_]*)*?(/[A-Z%0-9_]*\.[A-Z%0-9_]*)?([#?][A-Z%0-9=&]*){0,2}$", "i")
+pub fn url() -> Regex {
+    regex_with_code(r"^(https?://)?([A-Z]+\.)+[A-Z]{2,}(/[A-Z%0-9_]*)*?(/[A-Z%0-9_]*\.[A-Z%0-9_]*)?([#?][A-Z%0-9=&]*){0,2}$", "i", "accord.url.invalid")
 }
 
 #[cfg(test)]
@@ -53,59 +77,59 @@ mod tests
     // regex
     #[test]
     pub fn regex_valid() {
-        assert!(regex(r"^a...$", "")(&"a123".to_owned()).is_ok());
-        assert!(regex(r"^a...$", "")(&"abcd".to_owned()).is_ok());
-        assert!(regex(r"^a...$", "")(&"a!?£".to_owned()).is_ok());
+        assert!(regex(r"^a...$", "").validate(&"a123".to_owned()).is_ok());
+        assert!(regex(r"^a...$", "").validate(&"abcd".to_owned()).is_ok());
+        assert!(regex(r"^a...$", "").validate(&"a!?£".to_owned()).is_ok());
     }
     #[test]
     pub fn regex_invalid() {
-        assert!(regex(r"^a...$", "")(&"a".to_owned()).is_err());
-        assert!(regex(r"^a...$", "")(&"abc".to_owned()).is_err());
-        assert!(regex(r"^a...$", "")(&"a123457".to_owned()).is_err());
-        assert!(regex(r"^a...$", "")(&"b".to_owned()).is_err());
-        assert!(regex(r"^a...$", "")(&"baaa".to_owned()).is_err());
+        assert!(regex(r"^a...$", "").validate(&"a".to_owned()).is_err());
+        assert!(regex(r"^a...$", "").validate(&"abc".to_owned()).is_err());
+        assert!(regex(r"^a...$", "").validate(&"a123457".to_owned()).is_err());
+        assert!(regex(r"^a...$", "").validate(&"b".to_owned()).is_err());
+        assert!(regex(r"^a...$", "").validate(&"baaa".to_owned()).is_err());
     }
 
     // email
     #[test]
     pub fn email_valid() {
-        assert!(email()(&"asdf@asdf.com".to_owned()).is_ok());
-        assert!(email()(&"amazing.email.address@super.amazing.site.tk".to_owned()).is_ok());
-        assert!(email()(&"prick@legal.google".to_owned()).is_ok());
+        assert!(email().validate(&"asdf@asdf.com".to_owned()).is_ok());
+        assert!(email().validate(&"amazing.email.address@super.amazing.site.tk".to_owned()).is_ok());
+        assert!(email().validate(&"prick@legal.google".to_owned()).is_ok());
     }
 
     #[test]
     pub fn email_invalid() {
-        assert!(email()(&"you tried.".to_owned()).is_err());
-        assert!(email()(&"so close @super.amazing.site.tk".to_owned()).is_err());
-        assert!(email()(&"prick@legal.g".to_owned()).is_err());
-        assert!(email()(&"prick@important legal documents!.google".to_owned()).is_err());
+        assert!(email().validate(&"you tried.".to_owned()).is_err());
+        assert!(email().validate(&"so close @super.amazing.site.tk".to_owned()).is_err());
+        assert!(email().validate(&"prick@legal.g".to_owned()).is_err());
+        assert!(email().validate(&"prick@important legal documents!.google".to_owned()).is_err());
     }
 
     // url
     #[test]
     pub fn url_valid() {
-        assert!(url()(&"http://github.com".to_owned()).is_ok());
-        assert!(url()(&"https://github.com".to_owned()).is_ok());
-        assert!(url()(&"www.github.com".to_owned()).is_ok());
-        assert!(url()(&"github.com".to_owned()).is_ok());
-        assert!(url()(&"sub.domains.github.fr".to_owned()).is_ok());
-        assert!(url()(&"neet.cool".to_owned()).is_ok());
+        assert!(url().validate(&"http://github.com".to_owned()).is_ok());
+        assert!(url().validate(&"https://github.com".to_owned()).is_ok());
+        assert!(url().validate(&"www.github.com".to_owned()).is_ok());
+        assert!(url().validate(&"github.com".to_owned()).is_ok());
+        assert!(url().validate(&"sub.domains.github.fr".to_owned()).is_ok());
+        assert!(url().validate(&"neet.cool".to_owned()).is_ok());
         
-        assert!(url()(&"github.com/org/repo".to_owned()).is_ok());
-        assert!(url()(&"github.com/org/repo/logo.jpeg".to_owned()).is_ok());
-        assert!(url()(&"github.com/org/repo/README#Usage".to_owned()).is_ok());
-        assert!(url()(&"github.com/org/repo?folder=src&sort=desc".to_owned()).is_ok());
+        assert!(url().validate(&"github.com/org/repo".to_owned()).is_ok());
+        assert!(url().validate(&"github.com/org/repo/logo.jpeg".to_owned()).is_ok());
+        assert!(url().validate(&"github.com/org/repo/README#Usage".to_owned()).is_ok());
+        assert!(url().validate(&"github.com/org/repo?folder=src&sort=desc".to_owned()).is_ok());
     }
 
     #[test]
     pub fn url_invalid() {
-        assert!(url()(&"ftp://super.amazing.site".to_owned()).is_err());
-        assert!(url()(&"http/super.amazing.site".to_owned()).is_err());
-        assert!(url()(&"my awesome site!!.com".to_owned()).is_err());
-        assert!(url()(&"nonon.o".to_owned()).is_err());
-        assert!(url()(&"github.com/invalid file".to_owned()).is_err());
-        assert!(url()(&"github.com/file? bad query params".to_owned()).is_err());
-        assert!(url()(&"github.com/file# bad jump thing".to_owned()).is_err());
+        assert!(url().validate(&"ftp://super.amazing.site".to_owned()).is_err());
+        assert!(url().validate(&"http/super.amazing.site".to_owned()).is_err());
+        assert!(url().validate(&"my awesome site!!.com".to_owned()).is_err());
+        assert!(url().validate(&"nonon.o".to_owned()).is_err());
+        assert!(url().validate(&"github.com/invalid file".to_owned()).is_err());
+        assert!(url().validate(&"github.com/file? bad query params".to_owned()).is_err());
+        assert!(url().validate(&"github.com/file# bad jump thing".to_owned()).is_err());
     }
 }
\ No newline at end of file
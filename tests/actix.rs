@@ -0,0 +1,117 @@
+#![cfg(feature = "actix")]
+
+#[macro_use]
+extern crate accord;
+extern crate actix_web;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+use accord::{Accord, MultipleError, Result as AccordResult};
+use accord::actix::{Validated, ValidationConfig};
+use accord::validators::{contains, max, min};
+use actix_web::http::{header, StatusCode};
+use actix_web::test::{call_service, init_service, read_body, TestRequest};
+use actix_web::{error, rt, web, App, HttpResponse};
+
+#[derive(Deserialize)]
+struct Account {
+    pub name: String,
+    pub email: String,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [max(16)],
+            "email" => self.email => [min(5), contains("@")]
+        }
+    }
+}
+
+async fn json(Validated(web::Json(account)): Validated<web::Json<Account>>) -> String {
+    account.name
+}
+
+async fn query(Validated(web::Query(account)): Validated<web::Query<Account>>) -> String {
+    account.name
+}
+
+async fn form(Validated(web::Form(account)): Validated<web::Form<Account>>) -> String {
+    account.name
+}
+
+#[test]
+fn main() {
+    rt::System::new().block_on(async {
+        let app = init_service(App::new()
+            .route("/json", web::post().to(json))
+            .route("/query", web::get().to(query))
+            .route("/form", web::post().to(form))).await;
+
+        let request = TestRequest::post()
+            .uri("/json")
+            .set_json(serde_json::json!({ "name": "test", "email": "test@test.test" }))
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_body(response).await, "test");
+
+        let request = TestRequest::post()
+            .uri("/json")
+            .set_json(serde_json::json!({ "name": "test", "email": "test" }))
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let error: MultipleError = serde_json::from_slice(&read_body(response).await).unwrap();
+        assert_eq!(error.0[0].tag, "email");
+        assert_eq!(error.0[0].invalids[1].code, "accord.contains.missing");
+
+        let request = TestRequest::post()
+            .uri("/json")
+            .insert_header((header::CONTENT_TYPE, "application/json"))
+            .set_payload(r#"{"name": "test""#)
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let error: MultipleError = serde_json::from_slice(&read_body(response).await).unwrap();
+        assert_eq!(error.0[0].tag, "");
+        assert_eq!(error.0[0].invalids[0].code, "accord.body.syntax_error");
+
+        let request = TestRequest::get().uri("/query?name=test&email=test%40test.test").to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = TestRequest::get().uri("/query?name=test").to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let error: MultipleError = serde_json::from_slice(&read_body(response).await).unwrap();
+        assert_eq!(error.0[0].invalids[0].code, "accord.query.invalid_data");
+
+        let request = TestRequest::post()
+            .uri("/form")
+            .set_form([("name", "a name that is way too long"), ("email", "test@test.test")])
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let error: MultipleError = serde_json::from_slice(&read_body(response).await).unwrap();
+        assert_eq!(error.0[0].tag, "name");
+
+        // a configured error handler
+        let app = init_service(App::new()
+            .app_data(ValidationConfig::default().error_handler(|err, _| {
+                let response = HttpResponse::BadRequest().body(err.error.to_string());
+                error::InternalError::from_response(err, response).into()
+            }))
+            .route("/json", web::post().to(json))).await;
+
+        let request = TestRequest::post()
+            .uri("/json")
+            .set_json(serde_json::json!({ "name": "test", "email": "test" }))
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(read_body(response).await,
+                   "email: Must contain more than 5 characters\nemail: Must contain '@'");
+    });
+}
@@ -0,0 +1,55 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate serde_json;
+
+use accord::{Arg, MultipleError};
+use accord::validators::{either, max_items, range};
+
+struct Order {
+    pub quantity: u32,
+    pub size: &'static str,
+    pub tags: Vec<String>,
+}
+
+#[cfg(not(feature = "inclusive_range"))]
+fn validate(order: &Order) -> Result<(), MultipleError> {
+    rules!{
+        "quantity" => order.quantity => [range(1, 10)],
+        "size" => order.size => [either(vec!["S", "M", "L"])],
+        "tags" => order.tags => [max_items(2)]
+    }
+}
+
+#[cfg(feature = "inclusive_range")]
+fn validate(order: &Order) -> Result<(), MultipleError> {
+    rules!{
+        "quantity" => order.quantity => [range(1..=10)],
+        "size" => order.size => [either(vec!["S", "M", "L"])],
+        "tags" => order.tags => [max_items(2)]
+    }
+}
+
+#[test]
+fn main() {
+    let order = Order {
+        quantity: 12,
+        size: "XL",
+        tags: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+    };
+
+    let error = validate(&order).unwrap_err();
+    let quantity = &error.0[0].invalids[0];
+    let size = &error.0[1].invalids[0];
+
+    assert_eq!(quantity.args, vec![Arg::named("min", &1u32), Arg::named("max", &10u32)]);
+    assert_eq!(quantity.string_args(), vec!["1".to_string(), "10".to_string()]);
+    assert_eq!(size.human_readable, "Must be one of S, M, L");
+    assert_eq!(size.string_args(), vec!["S, M, L".to_string()]);
+
+    let json = serde_json::to_value(&error).unwrap();
+    assert_eq!(json[0]["invalids"][0]["args"],
+               json!([{ "name": "min", "value": 1 }, { "name": "max", "value": 10 }]));
+    assert_eq!(json[1]["invalids"][0]["args"], json!([["S", "M", "L"]]));
+    assert_eq!(json[2]["invalids"][0]["args"], json!([{ "name": "max", "value": 2 }]));
+}
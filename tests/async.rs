@@ -0,0 +1,134 @@
+#[macro_use]
+extern crate accord;
+extern crate futures;
+
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+
+use accord::{Accord, AsyncAccord, AsyncValidator, BoxFuture, Invalid, Result as AccordResult,
+             ValidatorResult};
+use accord::validators::{contains, max, min, nested};
+use futures::executor::block_on;
+
+/// In-memory stand-in for a database of user names.
+struct Users {
+    taken: HashSet<String>,
+    log: Mutex<Vec<String>>,
+}
+
+impl Users {
+    async fn exists(&self, name: &str) -> bool {
+        self.log.lock().unwrap().push(format!("start {}", name));
+        YieldOnce(false).await;
+        self.log.lock().unwrap().push(format!("end {}", name));
+        self.taken.contains(name)
+    }
+}
+
+/// Returns pending once, so other futures get to run in the meantime.
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+struct Available<'a>(&'a Users);
+
+impl<'a> AsyncValidator<String> for Available<'a> {
+    type Output = ValidatorResult;
+
+    fn validate_async<'b>(&'b self, name: &'b String) -> BoxFuture<'b, ValidatorResult> {
+        Box::pin(async move {
+            if self.0.exists(name).await {
+                Err(Invalid {
+                    code: "signup.username.taken".to_string(),
+                    msg: "Must not be taken.".to_string(),
+                    args: vec![],
+                    human_readable: "Must not be taken".to_string(),
+                })
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+struct Address {
+    pub zip: String,
+}
+
+impl Accord for Address {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "zip" => self.zip => [min(4)]
+        }
+    }
+}
+
+struct Signup<'a> {
+    pub users: &'a Users,
+    pub username: String,
+    pub nickname: String,
+    pub email: String,
+    pub address: Address,
+}
+
+impl<'a> AsyncAccord for Signup<'a> {
+    fn validate_async(&self) -> BoxFuture<'_, AccordResult> {
+        Box::pin(async_rules!{
+            "username" => self.username => [max(16), Available(self.users)],
+            "nickname" => self.nickname => [Available(self.users)],
+            "email" => self.email => [contains("@")],
+            "address" => self.address => [nested()]
+        })
+    }
+}
+
+#[test]
+fn main() {
+    let users = Users {
+        taken: vec!["admin".to_string()].into_iter().collect(),
+        log: Mutex::new(Vec::new()),
+    };
+
+    let okay = Signup {
+        users: &users,
+        username: "test".to_string(),
+        nickname: "tester".to_string(),
+        email: "test@test.test".to_string(),
+        address: Address { zip: "1234".to_string() },
+    };
+
+    assert!(block_on(okay.validate_async()).is_ok());
+
+    // both lookups are running at the same time
+    assert_eq!(*users.log.lock().unwrap(),
+               vec!["start test", "start tester", "end test", "end tester"]);
+
+    let error = Signup {
+        users: &users,
+        username: "admin".to_string(),
+        nickname: "admin".to_string(),
+        email: "test".to_string(),
+        address: Address { zip: "12".to_string() },
+    };
+
+    let error = block_on(error.validate_async()).unwrap_err();
+    let tags = error.0.iter().map(|m| m.tag.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(tags, vec!["username", "nickname", "email", "address.zip"]);
+    assert_eq!(error.0[0].invalids[0].msg, "Must not be taken.");
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "axum")]
+
+#[macro_use]
+extern crate accord;
+extern crate axum;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate tokio;
+extern crate tower;
+
+use accord::{Accord, MultipleError, Result as AccordResult};
+use accord::axum::Validated;
+use accord::validators::{contains, max, min};
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Request, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use tower::ServiceExt;
+
+#[derive(Deserialize)]
+struct Account {
+    pub name: String,
+    pub email: String,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [max(16)],
+            "email" => self.email => [min(5), contains("@")]
+        }
+    }
+}
+
+async fn create(Validated(Json(account)): Validated<Json<Account>>) -> String {
+    account.name
+}
+
+async fn post_json(body: &'static str, content_type: &str) -> (StatusCode, Vec<u8>) {
+    let app = Router::new().route("/", post(create));
+    let request = Request::post("/")
+        .header(header::CONTENT_TYPE, content_type)
+        .body(Body::from(body))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    (status, to_bytes(response.into_body(), usize::MAX).await.unwrap().to_vec())
+}
+
+#[tokio::test]
+async fn main() {
+    let (status, body) = post_json(r#"{"name": "test", "email": "test@test.test"}"#,
+                                   "application/json").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, b"test");
+
+    let (status, body) = post_json(r#"{"name": "test", "email": "test"}"#, "application/json")
+        .await;
+    let error: MultipleError = serde_json::from_slice(&body).unwrap();
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(error.0[0].tag, "email");
+    assert_eq!(error.0[0].invalids[0].code, "accord.length.too_short");
+    assert_eq!(error.0[0].invalids[1].code, "accord.contains.missing");
+
+    // deserialization errors come in the same shape
+    let (status, body) = post_json(r#"{"name": "test""#, "application/json").await;
+    let error: MultipleError = serde_json::from_slice(&body).unwrap();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error.0[0].tag, "");
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.syntax_error");
+
+    let (status, body) = post_json(r#"{"name": "test"}"#, "application/json").await;
+    let error: MultipleError = serde_json::from_slice(&body).unwrap();
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.invalid_data");
+
+    let (status, body) = post_json(r#"{"name": "test"}"#, "text/plain").await;
+    let error: MultipleError = serde_json::from_slice(&body).unwrap();
+    assert_eq!(status, StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.unsupported_media_type");
+}
@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate accord;
+
+use accord::{Catalog, MultipleError};
+use accord::validators::{contains, max};
+
+const DE: &str = r#"
+msgid "Must contain %1."
+msgstr "Muss %1 enthalten."
+
+msgid "Must not contain more characters than %1."
+msgstr "Darf nicht mehr als %1 Zeichen enthalten."
+"#;
+
+const DA: &str = r#"{
+    "accord.contains.missing": "Skal indeholde %1.",
+    "Must contain %1.": "Ikke brugt."
+}"#;
+
+fn validate(email: &String) -> Result<(), MultipleError> {
+    rules!{
+        "email" => email => [contains("@"), contains("."), max(5)]
+    }
+}
+
+#[test]
+fn main() {
+    let mut catalog = Catalog::new();
+    catalog.add_po("de", DE).unwrap();
+    catalog.add_json("da", DA).unwrap();
+    assert!(catalog.add_json("sv", "[]").is_err());
+
+    let error = validate(&"testtest".to_string()).unwrap_err();
+    let invalids = &error.0[0].invalids;
+
+    let render = |locale: &str| {
+        invalids.iter().map(|i| catalog.render(i, locale)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(render("de-AT"),
+               vec!["Muss @ enthalten.", "Muss . enthalten.", "Darf nicht mehr als 5 Zeichen enthalten."]);
+    assert_eq!(render("da"),
+               vec!["Skal indeholde @.", "Skal indeholde ..", "Must not contain more characters than 5."]);
+    assert_eq!(render("en"),
+               vec!["Must contain @.", "Must contain ..", "Must not contain more characters than 5."]);
+
+    let localized = catalog.localize(&error, "da");
+    assert_eq!(localized.0[0].invalids[0].human_readable, "Skal indeholde @.");
+    assert_eq!(localized.0[0].invalids[2].human_readable, "Must contain less than 5 characters");
+}
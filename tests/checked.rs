@@ -0,0 +1,138 @@
+#![cfg(feature = "serde_path_to_error")]
+
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde;
+extern crate serde_json;
+
+use std::collections::HashMap;
+
+use accord::{Accord, Result as AccordResult};
+use accord::serde::{self as accord_serde, Checked, Rule, Validated};
+use accord::validators::{alphanumeric, contains, max, min};
+use serde::de::DeserializeOwned;
+
+rule!(Username: String => [max(16), alphanumeric()]);
+rule!(Zip: String => [min(4)]);
+rule!(Short: String => [max(4)]);
+
+#[derive(Debug, Deserialize)]
+struct Address {
+    pub street: String,
+    pub zip: Checked<String, Zip>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Contact {
+    pub email: String,
+}
+
+impl Accord for Contact {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [contains("@")]
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Signup {
+    pub username: Checked<String, Username>,
+    #[serde(deserialize_with = "accord::serde::check::<Username, _, _>")]
+    pub nickname: String,
+    pub addresses: Vec<Address>,
+    pub contact: Validated<Contact>,
+    pub age: u8,
+    #[serde(default)]
+    pub zips: HashMap<String, Checked<String, Zip>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Name {
+    Short(Checked<String, Short>),
+    Long(String),
+}
+
+#[derive(Debug, Deserialize)]
+struct Person {
+    pub name: Name,
+    pub age: u8,
+}
+
+fn deserialize<T: DeserializeOwned>(json: &str) -> Result<T, accord::MultipleError> {
+    accord_serde::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn main() {
+    let json = r#"{
+        "username": "test",
+        "nickname": "tester",
+        "addresses": [{ "street": "Main Street", "zip": "1234" }],
+        "contact": { "email": "test@test.test" },
+        "age": 25
+    }"#;
+
+    let signup: Signup = deserialize(json).unwrap();
+    assert_eq!(*signup.username, "test");
+    assert_eq!(signup.nickname, "tester");
+    assert_eq!(signup.addresses[0].zip.as_str(), "1234");
+    assert_eq!(signup.addresses[0].street, "Main Street");
+    assert_eq!(signup.contact.email, "test@test.test");
+    assert_eq!(signup.age, 25);
+    assert!(signup.zips.is_empty());
+
+    // the first invalid field stops deserialization
+    let error = deserialize::<Signup>(&json.replace("\"test\"", "\"not valid!\"")).unwrap_err();
+    assert_eq!(error.0[0].tag, "username");
+    assert_eq!(error.0[0].invalids[0].code, "accord.contain_only.invalid_char");
+
+    let error = deserialize::<Signup>(&json.replace("tester", "a nickname that is way too long")).unwrap_err();
+    assert_eq!(error.0[0].tag, "nickname");
+    assert_eq!(error.0[0].invalids[0].code, "accord.length.too_long");
+
+    let error = deserialize::<Signup>(&json.replace("1234", "12")).unwrap_err();
+    assert_eq!(error.0[0].tag, "addresses[0].zip");
+    assert_eq!(error.0[0].invalids[0].code, "accord.length.too_short");
+
+    let error = deserialize::<Signup>(&json.replace("test@test.test", "test")).unwrap_err();
+    assert_eq!(error.0[0].tag, "contact.email");
+    assert_eq!(error.0[0].invalids[0].code, "accord.contains.missing");
+
+    let error = deserialize::<Signup>(&json.replace("\"age\"", "\"zips\": { \"head office\": \"12\" }, \"age\""))
+        .unwrap_err();
+    assert_eq!(error.0[0].tag, "zips[\"head office\"]");
+    assert_eq!(error.0[0].invalids[0].code, "accord.length.too_short");
+
+    // other errors keep their Serde message
+    let error = deserialize::<Signup>(&json.replace("25", "-1")).unwrap_err();
+    assert_eq!(error.0[0].tag, "age");
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.invalid_data");
+
+    // without `deserialize` the errors are plain Serde errors
+    let error = serde_json::from_str::<Signup>(&json.replace("1234", "12")).unwrap_err();
+    assert!(error.to_string().starts_with("Must contain more than 4 characters"));
+
+    // errors an untagged enum recovered from are not reported for later errors
+    let person = deserialize::<Person>(r#"{ "name": "abcdef", "age": 25 }"#).unwrap();
+    assert_eq!(person.age, 25);
+    match person.name {
+        Name::Long(name) => assert_eq!(name, "abcdef"),
+        Name::Short(name) => panic!("expected a long name, got {}", *name),
+    }
+    let error = deserialize::<Person>(r#"{ "name": "abcdef", "age": "old" }"#).unwrap_err();
+    assert_eq!(error.0[0].tag, "age");
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.invalid_data");
+    let error = deserialize::<Person>(r#"{ "name": "abc", "age": "old" }"#).unwrap_err();
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.invalid_data");
+
+    // which are not kept for later calls of `deserialize`
+    let error = deserialize::<Signup>(&json.replace("25", "-1")).unwrap_err();
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.invalid_data");
+
+    assert!(Username::check(&"test".to_string()).is_ok());
+    assert!(Checked::<String, Username>::new("not valid!".to_string()).is_err());
+}
@@ -0,0 +1,28 @@
+#[macro_use]
+extern crate accord;
+extern crate serde_json;
+
+use accord::MultipleError;
+use accord::validators::{alphanumeric, not_contain};
+
+fn validate(username: &String) -> Result<(), MultipleError> {
+    rules!{
+        "username" => username => [alphanumeric(), not_contain("!")]
+    }
+}
+
+#[test]
+fn main() {
+    let error = validate(&"admin!".to_string()).unwrap_err();
+    let invalids = &error.0[0].invalids;
+
+    // both errors share their message, but not their code
+    assert_eq!(invalids[0].msg, invalids[1].msg);
+    assert_eq!(invalids[0].code, "accord.contain_only.invalid_char");
+    assert_eq!(invalids[1].code, "accord.not_contain.present");
+
+    let json = serde_json::to_value(&error).unwrap();
+    assert_eq!(json[0]["invalids"][0]["code"], "accord.contain_only.invalid_char");
+    assert_eq!(json[0]["invalids"][0]["msg"], "Must not contain %1.");
+    assert_eq!(json[0]["invalids"][0]["args"][0], "!");
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate accord;
+
+use std::collections::HashMap;
+
+use accord::{Accord, Result as AccordResult};
+use accord::validators::{alphanumeric, each, each_key, each_value, length};
+
+struct Post {
+    pub tags: Vec<String>,
+    pub labels: HashMap<String, String>,
+}
+
+impl Accord for Post {
+    #[cfg(not(feature = "inclusive_range"))]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "tags" => self.tags => [each((length(1, 32), alphanumeric()))],
+            "labels" => self.labels => [each_key(vec![alphanumeric()]), each_value(vec![length(1, 32)])]
+        }
+    }
+
+    #[cfg(feature = "inclusive_range")]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "tags" => self.tags => [each((length(1..=32), alphanumeric()))],
+            "labels" => self.labels => [each_key(vec![alphanumeric()]), each_value(vec![length(1..=32)])]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let mut labels = HashMap::new();
+    labels.insert("env".to_string(), "prod".to_string());
+
+    let okay = Post {
+        tags: vec!["rust".to_string(), "validation".to_string()],
+        labels,
+    };
+
+    let mut labels = HashMap::new();
+    labels.insert("env".to_string(), "".to_string());
+    labels.insert("team name".to_string(), "core".to_string());
+
+    let error = Post {
+        tags: vec!["rust".to_string(), "".to_string(), "ok".to_string(), "not ok".to_string()],
+        labels,
+    };
+
+    assert!(okay.validate().is_ok());
+
+    let mut tags = error.validate()
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|m| m.tag)
+        .collect::<Vec<_>>();
+    tags.sort();
+    assert_eq!(tags,
+               vec!["labels[\"env\"]", "labels[\"team name\"]", "tags[1]", "tags[3]"]);
+}
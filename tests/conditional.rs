@@ -0,0 +1,85 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate accord_derive;
+
+use accord::{Accord, Error, Result as AccordResult};
+use accord::validators::{contains, either, max, min, unless, when};
+
+#[derive(PartialEq)]
+enum AccountType {
+    Personal,
+    Business,
+}
+
+struct Account {
+    pub account_type: AccountType,
+    pub company_name: String,
+    pub country: String,
+    pub vat_number: String,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        let eu = ["DK", "DE", "FR"].contains(&self.country.as_str());
+        rules!{
+            "company_name" => self.company_name => [
+                when(self.account_type == AccountType::Business, (min(1), max(64)))
+            ],
+            "vat_number" => self.vat_number => [unless(!eu, (min(8), max(14)))]
+        }
+    }
+}
+
+fn has_carrier(shipment: &Shipment) -> AccordResult {
+    rules!{ "carrier" => shipment.carrier => [min(1)] }
+}
+
+#[derive(Accord)]
+#[accord(when(|s: &Shipment| s.express, vec![has_carrier]))]
+struct Shipment {
+    pub express: bool,
+    #[accord(when(self.express, vec![either(vec!["DHL".to_string(), "UPS".to_string()])]))]
+    pub carrier: String,
+}
+
+#[test]
+fn main() {
+    let personal = Account {
+        account_type: AccountType::Personal,
+        company_name: "".to_string(),
+        country: "US".to_string(),
+        vat_number: "".to_string(),
+    };
+
+    let business = Account {
+        account_type: AccountType::Business,
+        company_name: "".to_string(),
+        country: "DK".to_string(),
+        vat_number: "".to_string(),
+    };
+
+    assert!(personal.validate().is_ok());
+
+    let tags = business.validate()
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|m| m.tag)
+        .collect::<Vec<_>>();
+    assert_eq!(tags, vec!["company_name", "vat_number"]);
+
+    let name = "foo".to_string();
+    let result: Result<(), Error> = rules!(name, [when(true, vec![contains("@"), contains(".")])]);
+    assert_eq!(result.unwrap_err().0.len(), 2);
+    let result: Result<(), Error> = rules!(name, [when(false, vec![contains("@")])]);
+    assert!(result.is_ok());
+
+    assert!(Shipment { express: false, carrier: "Post".to_string() }.validate().is_ok());
+    assert!(Shipment { express: true, carrier: "DHL".to_string() }.validate().is_ok());
+    assert!(Shipment { express: true, carrier: "Post".to_string() }.validate().is_err());
+    assert!(Shipment { express: false, carrier: "".to_string() }.validate().is_ok());
+    let error = Shipment { express: true, carrier: "".to_string() }.validate().unwrap_err();
+    assert_eq!(error.0[0].tag, "carrier");
+    assert_eq!(error.0[0].invalids.len(), 2);
+}
@@ -0,0 +1,95 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate accord_derive;
+
+use std::collections::HashMap;
+
+use accord::{AccordWith, Arg, Invalid, Result as AccordResult, ValidatorResult};
+use accord::validators::{each, nested_with, range};
+
+struct Tenant {
+    pub country: &'static str,
+    pub stock: HashMap<String, u32>,
+}
+
+impl Tenant {
+    fn stock(&self, sku: &str) -> u32 {
+        self.stock.get(sku).cloned().unwrap_or(0)
+    }
+}
+
+/// Postal codes are four digits in Denmark and five everywhere else.
+fn postal_code(tenant: &Tenant) -> impl Fn(&String) -> ValidatorResult {
+    let digits = if tenant.country == "DK" { 4 } else { 5 };
+    move |code: &String| {
+        if code.len() == digits && code.chars().all(|c| c.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err(Invalid {
+                code: "tenant.postal_code.invalid".to_string(),
+                msg: "Must be a postal code of %1 digits.".to_string(),
+                args: vec![Arg::from(&digits)],
+                human_readable: format!("Must be a postal code of {} digits", digits),
+            })
+        }
+    }
+}
+
+#[cfg(not(feature = "inclusive_range"))]
+#[derive(Accord)]
+#[accord(context = Tenant)]
+struct OrderLine {
+    pub sku: String,
+    #[accord(range(1, ctx.stock(&self.sku)))]
+    pub quantity: u32,
+}
+
+#[cfg(feature = "inclusive_range")]
+#[derive(Accord)]
+#[accord(context = Tenant)]
+struct OrderLine {
+    pub sku: String,
+    #[accord(range(1..=ctx.stock(&self.sku)))]
+    pub quantity: u32,
+}
+
+struct Order {
+    pub postal_code: String,
+    pub lines: Vec<OrderLine>,
+}
+
+impl AccordWith<Tenant> for Order {
+    fn validate_with(&self, tenant: &Tenant) -> AccordResult {
+        rules!{
+            "postal_code" => self.postal_code => [postal_code(tenant)],
+            "lines" => self.lines => [each(vec![nested_with(tenant)])]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let mut stock = HashMap::new();
+    stock.insert("apple".to_string(), 10);
+    stock.insert("pear".to_string(), 2);
+    let dk = Tenant { country: "DK", stock: stock.clone() };
+    let us = Tenant { country: "US", stock };
+
+    let order = Order {
+        postal_code: "8000".to_string(),
+        lines: vec![OrderLine { sku: "apple".to_string(), quantity: 5 },
+                    OrderLine { sku: "pear".to_string(), quantity: 3 },
+                    OrderLine { sku: "apple".to_string(), quantity: 11 }],
+    };
+
+    assert!(order.lines[0].validate_with(&dk).is_ok());
+
+    let error = order.validate_with(&dk).unwrap_err();
+    let tags = error.0.iter().map(|m| m.tag.as_str()).collect::<Vec<_>>();
+    assert_eq!(tags, vec!["lines[1].quantity", "lines[2].quantity"]);
+
+    let error = order.validate_with(&us).unwrap_err();
+    let tags = error.0.iter().map(|m| m.tag.as_str()).collect::<Vec<_>>();
+    assert_eq!(tags, vec!["postal_code", "lines[1].quantity", "lines[2].quantity"]);
+}
@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate accord_derive;
+
+use accord::{Accord, Invalid, Result as AccordResult, ValidatorResult};
+use accord::validators::{eq, min};
+
+#[derive(Accord)]
+#[accord(passwords_match)]
+struct Signup {
+    #[accord(min(8))]
+    pub password: String,
+    pub password_confirmation: String,
+}
+
+fn passwords_match(signup: &Signup) -> AccordResult {
+    rules!{
+        "password_confirmation" => signup.password_confirmation => [eq(signup.password.clone())]
+    }
+}
+
+struct Booking {
+    pub start_date: u32,
+    pub end_date: u32,
+}
+
+fn ends_after_start(booking: &Booking) -> ValidatorResult {
+    if booking.end_date > booking.start_date {
+        Ok(())
+    } else {
+        Err(Invalid {
+            code: "booking.dates.order".to_string(),
+            msg: "Must end after it starts.".to_string(),
+            args: vec![],
+            human_readable: "Must end after it starts".to_string(),
+        })
+    }
+}
+
+impl Accord for Booking {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            self => [ends_after_start],
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let okay = Signup {
+        password: "kfjsdkfjsdkfjfksjdfkdsfjs".to_string(),
+        password_confirmation: "kfjsdkfjsdkfjfksjdfkdsfjs".to_string(),
+    };
+
+    let error = Signup {
+        password: "short".to_string(),
+        password_confirmation: "shorter".to_string(),
+    };
+
+    assert!(okay.validate().is_ok());
+
+    let tags = error.validate()
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|m| m.tag)
+        .collect::<Vec<_>>();
+    assert_eq!(tags, vec!["password", "password_confirmation"]);
+
+    assert!(Booking { start_date: 1, end_date: 2 }.validate().is_ok());
+
+    let error = Booking { start_date: 2, end_date: 1 }.validate().unwrap_err();
+    assert_eq!(error.0.len(), 1);
+    assert_eq!(error.0[0].tag, "");
+    assert_eq!(error.0[0].invalids[0].code, "booking.dates.order");
+}
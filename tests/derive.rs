@@ -0,0 +1,59 @@
+extern crate accord;
+#[macro_use]
+extern crate accord_derive;
+
+use accord::Accord;
+use accord::validators::{length, contains, range, alphanumeric_dashes};
+
+#[cfg(not(feature = "inclusive_range"))]
+#[derive(Accord)]
+struct Account {
+    #[accord(length(1, 64))]
+    pub name: String,
+    #[accord(alphanumeric_dashes())]
+    pub username: String,
+    #[accord(length(5, 64), contains("@"), contains("."))]
+    pub email: String,
+    #[accord(range(12, 127))]
+    pub age: i8,
+}
+
+#[cfg(feature = "inclusive_range")]
+#[derive(Accord)]
+struct Account {
+    #[accord(length(1..=64))]
+    pub name: String,
+    #[accord(alphanumeric_dashes())]
+    pub username: String,
+    #[accord(length(5..=64), contains("@"), contains("."))]
+    pub email: String,
+    #[accord(range(12..=127))]
+    pub age: i8,
+}
+
+#[test]
+fn main() {
+    let okay = Account {
+        name: "Test Test".to_string(),
+        username: "my_radical-username".to_string(),
+        email: "test@test.test".to_string(),
+        age: 25,
+    };
+
+    let error = Account {
+        name: "Test".to_string(),
+        username: "wow what a radical username dude!!!!".to_string(),
+        email: "testtest.test".to_string(),
+        age: 11,
+    };
+
+    assert!(okay.validate().is_ok());
+
+    let tags = error.validate()
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|m| m.tag)
+        .collect::<Vec<_>>();
+    assert_eq!(tags, vec!["username", "email", "age"]);
+}
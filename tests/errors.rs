@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate accord;
+
+use std::error::Error as StdError;
+
+use accord::{Accord, Error, MultipleError, Result as AccordResult};
+use accord::validators::{contains, max, min};
+
+struct Account {
+    pub name: String,
+    pub email: String,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [max(8)],
+            "email" => self.email => [min(5), contains("@")]
+        }
+    }
+}
+
+fn signup(account: &Account) -> Result<(), Box<dyn StdError>> {
+    account.validate()?;
+    Ok(())
+}
+
+fn check_name(name: &String) -> Result<(), Error> {
+    rules!(name, [max(8)])
+}
+
+#[test]
+fn main() {
+    let okay = Account {
+        name: "test".to_string(),
+        email: "test@test.test".to_string(),
+    };
+
+    let error = Account {
+        name: "a name that is too long".to_string(),
+        email: "t".to_string(),
+    };
+
+    assert!(signup(&okay).is_ok());
+    assert_eq!(signup(&error).unwrap_err().to_string(),
+               "name: Must contain less than 8 characters\n\
+                email: Must contain more than 5 characters\n\
+                email: Must contain '@'");
+
+    let error = check_name(&error.name).unwrap_err();
+    assert_eq!(error.to_string(), "Must contain less than 8 characters");
+
+    let error: MultipleError = error.tagged("name");
+    assert_eq!(error.0[0].tag, "name");
+    assert_eq!(error.to_string(), "name: Must contain less than 8 characters");
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "graphql")]
+
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate serde_json;
+
+use accord::{Accord, PathSegment, Result as AccordResult};
+use accord::graphql;
+use accord::validators::{alphanumeric, contains, each, min, nested};
+
+struct Address {
+    pub zip: String,
+}
+
+impl Accord for Address {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "zip" => self.zip => [min(4)]
+        }
+    }
+}
+
+struct CreateAccountInput {
+    pub email: String,
+    pub addresses: Vec<Address>,
+    pub tags: Vec<String>,
+}
+
+impl Accord for CreateAccountInput {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [contains("@")],
+            "addresses" => self.addresses => [each([nested()])],
+            "tags" => self.tags => [each([alphanumeric()])]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let input = CreateAccountInput {
+        email: "test".to_string(),
+        addresses: vec![Address { zip: "1234".to_string() }, Address { zip: "12".to_string() }],
+        tags: vec!["ok".to_string()],
+    };
+
+    let error = input.validate().unwrap_err();
+    let errors = graphql::errors(&error);
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[1].extensions.field, "addresses[1].zip");
+    assert_eq!(errors[1].extensions.path,
+               vec![PathSegment::Key("addresses".to_string()),
+                    PathSegment::Index(1),
+                    PathSegment::Key("zip".to_string())]);
+
+    assert_eq!(serde_json::to_value(&errors).unwrap()[1], json!({
+        "message": "Must contain more than 4 characters",
+        "extensions": {
+            "code": "accord.length.too_short",
+            "field": "addresses[1].zip",
+            "path": ["addresses", 1, "zip"],
+            "msg": "Must contain more than %1 characters",
+            "args": [{ "name": "min", "value": 4 }]
+        }
+    }));
+}
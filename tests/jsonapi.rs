@@ -0,0 +1,76 @@
+#![cfg(feature = "jsonapi")]
+
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate serde_json;
+
+use std::collections::HashMap;
+
+use accord::{Accord, Result as AccordResult};
+use accord::jsonapi::Document;
+use accord::validators::{alphanumeric, contains, each, each_value, max, min, nested};
+
+struct Address {
+    pub zip: String,
+}
+
+impl Accord for Address {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "zip" => self.zip => [min(4)]
+        }
+    }
+}
+
+struct Account {
+    pub email: String,
+    pub address: Address,
+    pub tags: Vec<String>,
+    pub labels: HashMap<String, String>,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [contains("@")],
+            "address" => self.address => [nested()],
+            "tags" => self.tags => [each([alphanumeric()])],
+            "labels" => self.labels => [each_value([max(4)])]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let mut labels = HashMap::new();
+    labels.insert("team/name".to_string(), "too long".to_string());
+
+    let account = Account {
+        email: "test".to_string(),
+        address: Address { zip: "12".to_string() },
+        tags: vec!["ok".to_string(), "not ok".to_string()],
+        labels,
+    };
+
+    let error = account.validate().unwrap_err();
+    let document = Document::from(&error);
+    let pointers = document.errors.iter().map(|e| e.source.pointer.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(pointers, vec!["/data/attributes/email",
+                              "/data/attributes/address/zip",
+                              "/data/attributes/tags/1",
+                              "/data/attributes/labels/team~1name"]);
+
+    assert_eq!(serde_json::to_value(&document).unwrap()["errors"][0], json!({
+        "status": "422",
+        "code": "accord.contains.missing",
+        "title": "Must contain %1.",
+        "detail": "Must contain '@'",
+        "source": { "pointer": "/data/attributes/email" },
+        "meta": { "args": ["@"] }
+    }));
+
+    let document = Document::with_prefix(&error, "");
+    assert_eq!(document.errors[1].source.pointer, "/address/zip");
+}
@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate accord_derive;
+
+use accord::{Accord, Catalog, Result as AccordResult, Validator, ValidatorExt};
+use accord::validators::{contains, max, min, with_msg};
+
+#[derive(Accord)]
+struct Account {
+    #[accord(max(16).message("Please pick a name of at most %1 characters."))]
+    pub name: String,
+    #[accord(with_msg(contains("@"), "account.email.invalid", "Invalid email.", "That is not an email address"))]
+    pub email: String,
+}
+
+struct Password(String);
+
+impl Accord for Password {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "password" => self.0 => [min(8).message("Use %1 characters or more."), contains("!")]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let account = Account {
+        name: "a name that is way too long".to_string(),
+        email: "test".to_string(),
+    };
+
+    let error = account.validate().unwrap_err();
+    let name = &error.0[0].invalids[0];
+    let email = &error.0[1].invalids[0];
+
+    assert_eq!(name.code, "accord.length.too_long");
+    assert_eq!(name.msg, "Please pick a name of at most %1 characters.");
+    assert_eq!(name.string_args(), vec!["16".to_string()]);
+    assert_eq!(name.human_readable, "Please pick a name of at most 16 characters.");
+
+    assert_eq!(email.code, "account.email.invalid");
+    assert_eq!(email.msg, "Invalid email.");
+    assert_eq!(email.string_args(), vec!["@".to_string()]);
+    assert_eq!(email.human_readable, "That is not an email address");
+
+    let error = Password("secret".to_string()).validate().unwrap_err();
+    let invalids = &error.0[0].invalids;
+
+    assert_eq!(invalids[0].human_readable, "Use 8 characters or more.");
+    assert_eq!(invalids[1].human_readable, "Must contain '!'");
+
+    // `message` keeps the code, so translations of the code win over it, while `with_msg`
+    // gives the message a code that is translated by itself
+    let mut catalog = Catalog::new();
+    catalog.add_json("da", r#"{
+        "accord.length.too_long": "Maks %1 tegn.",
+        "signup.nickname.too_long": "Vælg et kort kaldenavn (%1)."
+    }"#).unwrap();
+
+    let nickname = "nickname".to_string();
+    let invalid = max(3).message("Pick a short nickname (%1).").validate(&nickname).unwrap_err();
+    assert_eq!(catalog.render(&invalid, "da"), "Maks 3 tegn.");
+    assert_eq!(catalog.render(&invalid, "fr"), "Pick a short nickname (3).");
+
+    let invalid = max(3)
+        .with_msg("signup.nickname.too_long",
+                  "Pick a short nickname (%1).",
+                  "Pick a short nickname (%1).")
+        .validate(&nickname)
+        .unwrap_err();
+    assert_eq!(catalog.render(&invalid, "da"), "Vælg et kort kaldenavn (3).");
+    assert_eq!(catalog.render(&invalid, "fr"), "Pick a short nickname (3).");
+}
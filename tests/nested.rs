@@ -0,0 +1,106 @@
+#[macro_use]
+extern crate accord;
+
+use accord::{Accord, Result as AccordResult};
+use accord::validators::{length, nested, range};
+
+struct Address {
+    pub street: String,
+    pub zip: String,
+}
+
+impl Accord for Address {
+    #[cfg(not(feature = "inclusive_range"))]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "street" => self.street => [length(1, 64)],
+            "zip" => self.zip => [length(4, 10)]
+        }
+    }
+
+    #[cfg(feature = "inclusive_range")]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "street" => self.street => [length(1..=64)],
+            "zip" => self.zip => [length(4..=10)]
+        }
+    }
+}
+
+struct User {
+    pub name: String,
+    pub age: i8,
+    pub address: Address,
+}
+
+impl Accord for User {
+    #[cfg(not(feature = "inclusive_range"))]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [length(1, 64)],
+            "age" => self.age => [range(12, 127)],
+            "address" => self.address => [nested()]
+        }
+    }
+
+    #[cfg(feature = "inclusive_range")]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [length(1..=64)],
+            "age" => self.age => [range(12..=127)],
+            "address" => self.address => [nested()]
+        }
+    }
+}
+
+struct Order {
+    pub customer: User,
+}
+
+impl Accord for Order {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "customer" => self.customer => [nested()]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let okay = User {
+        name: "Test Test".to_string(),
+        age: 25,
+        address: Address {
+            street: "Test Street 1".to_string(),
+            zip: "1234".to_string(),
+        },
+    };
+
+    let error = User {
+        name: "".to_string(),
+        age: 25,
+        address: Address {
+            street: "".to_string(),
+            zip: "12".to_string(),
+        },
+    };
+
+    assert!(okay.validate().is_ok());
+
+    let tags = error.validate()
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|m| m.tag)
+        .collect::<Vec<_>>();
+    assert_eq!(tags, vec!["name", "address.street", "address.zip"]);
+
+    let order = Order { customer: error };
+    let tags = order.validate()
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|m| m.tag)
+        .collect::<Vec<_>>();
+    assert_eq!(tags, vec!["customer.name", "customer.address.street", "customer.address.zip"]);
+}
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate accord;
+
+use accord::{Accord, Result as AccordResult};
+use accord::validators::{contains, either, optional, range, required};
+
+struct Profile {
+    pub email: Option<String>,
+    pub age: Option<i8>,
+    pub gender: Option<String>,
+}
+
+impl Accord for Profile {
+    #[cfg(not(feature = "inclusive_range"))]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [required(), optional(contains("@"))],
+            "age" => self.age => [optional(range(12, 127))],
+            "gender" => self.gender => [optional(either(vec!["female".to_string(), "male".to_string()]))]
+        }
+    }
+
+    #[cfg(feature = "inclusive_range")]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [required(), optional(contains("@"))],
+            "age" => self.age => [optional(range(12..=127))],
+            "gender" => self.gender => [optional(either(vec!["female".to_string(), "male".to_string()]))]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let okay = Profile {
+        email: Some("test@test.test".to_string()),
+        age: None,
+        gender: None,
+    };
+
+    let error = Profile {
+        email: None,
+        age: Some(11),
+        gender: Some("".to_string()),
+    };
+
+    assert!(okay.validate().is_ok());
+
+    let tags = error.validate()
+        .unwrap_err()
+        .0
+        .into_iter()
+        .map(|m| m.tag)
+        .collect::<Vec<_>>();
+    assert_eq!(tags, vec!["email", "age", "gender"]);
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate serde_json;
+
+use accord::{Accord, Catalog, ProblemDetails, Result as AccordResult};
+use accord::problem::CONTENT_TYPE;
+use accord::validators::{contains, max, min};
+
+struct Account {
+    pub name: String,
+    pub email: String,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [max(8)],
+            "email" => self.email => [min(5), contains("@")]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let account = Account {
+        name: "test".to_string(),
+        email: "t".to_string(),
+    };
+
+    let error = account.validate().unwrap_err();
+    let mut problem = error.to_problem_details();
+    problem.instance = Some("/accounts".to_string());
+
+    assert_eq!(CONTENT_TYPE, "application/problem+json");
+    assert_eq!(serde_json::to_value(&problem).unwrap(), json!({
+        "type": "about:blank",
+        "title": "Unprocessable Entity",
+        "status": 422,
+        "detail": "2 parameters are invalid.",
+        "instance": "/accounts",
+        "invalid-params": [
+            {
+                "name": "email",
+                "reason": "Must contain more than 5 characters",
+                "code": "accord.length.too_short"
+            },
+            {
+                "name": "email",
+                "reason": "Must contain '@'",
+                "code": "accord.contains.missing"
+            }
+        ]
+    }));
+
+    // localized reasons
+    let mut catalog = Catalog::new();
+    catalog.insert("da", "accord.contains.missing", "Skal indeholde %1.");
+    let problem = ProblemDetails::from(catalog.localize(&error, "da"));
+    assert_eq!(problem.invalid_params[1].reason, "Skal indeholde @.");
+
+    let body = serde_json::to_string(&problem).unwrap();
+    assert_eq!(serde_json::from_str::<ProblemDetails>(&body).unwrap(), problem);
+}
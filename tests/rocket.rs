@@ -0,0 +1,80 @@
+#![cfg(feature = "rocket")]
+
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate rocket;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+use accord::{Accord, MultipleError, Result as AccordResult};
+use accord::rocket::{Validated, ValidationError};
+use accord::validators::{contains, max, min};
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use rocket::serde::json::Json;
+
+#[derive(Deserialize)]
+struct Account {
+    pub name: String,
+    pub email: String,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [max(16)],
+            "email" => self.email => [min(5), contains("@")]
+        }
+    }
+}
+
+#[post("/", data = "<account>")]
+fn create(account: Result<Validated<Json<Account>>, ValidationError>)
+          -> Result<String, ValidationError> {
+    let Validated(Json(account)) = account?;
+    Ok(account.name)
+}
+
+#[post("/name", data = "<name>")]
+fn rename(name: String) -> Result<String, MultipleError> {
+    rules!{ "name" => name => [max(16)] }?;
+    Ok(name)
+}
+
+#[test]
+fn main() {
+    let client = Client::untracked(rocket::build().mount("/", routes![create, rename])).unwrap();
+    let post = |uri: &'static str, body: &'static str| {
+        client.post(uri).header(ContentType::JSON).body(body).dispatch()
+    };
+
+    let response = post("/", r#"{"name": "test", "email": "test@test.test"}"#);
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "test");
+
+    let response = post("/", r#"{"name": "test", "email": "test"}"#);
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+    let error: MultipleError = serde_json::from_str(&response.into_string().unwrap()).unwrap();
+    assert_eq!(error.0[0].tag, "email");
+    assert_eq!(error.0[0].invalids[1].code, "accord.contains.missing");
+
+    // malformed bodies don't panic, but are rejected in the same shape
+    let response = post("/", r#"{"name": "test""#);
+    assert_eq!(response.status(), Status::BadRequest);
+    let error: MultipleError = serde_json::from_str(&response.into_string().unwrap()).unwrap();
+    assert_eq!(error.0[0].tag, "");
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.syntax_error");
+
+    let response = post("/", r#"{"name": "test"}"#);
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    let error: MultipleError = serde_json::from_str(&response.into_string().unwrap()).unwrap();
+    assert_eq!(error.0[0].invalids[0].code, "accord.body.invalid_data");
+
+    let response = post("/name", "a name that is way too long");
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+    let error: MultipleError = serde_json::from_str(&response.into_string().unwrap()).unwrap();
+    assert_eq!(error.0[0].tag, "name");
+}
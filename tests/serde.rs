@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate serde_json;
+
+use accord::{Accord, Arg, Catalog, Error, MultipleError, Result as AccordResult};
+use accord::validators::{contains, either, range};
+
+struct Account {
+    pub email: String,
+    pub role: &'static str,
+    pub age: i8,
+}
+
+impl Accord for Account {
+    #[cfg(not(feature = "inclusive_range"))]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [contains("@")],
+            "role" => self.role => [either(vec!["user", "admin"])],
+            "age" => self.age => [range(12, 127)]
+        }
+    }
+
+    #[cfg(feature = "inclusive_range")]
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [contains("@")],
+            "role" => self.role => [either(vec!["user", "admin"])],
+            "age" => self.age => [range(12..=127)]
+        }
+    }
+}
+
+#[test]
+fn main() {
+    let account = Account {
+        email: "test".to_string(),
+        role: "root",
+        age: 11,
+    };
+
+    let error = account.validate().unwrap_err();
+    let json = serde_json::to_value(&error).unwrap();
+
+    // the documented shape of `MultipleError`
+    assert_eq!(json, json!([
+        {
+            "tag": "email",
+            "invalids": [{
+                "code": "accord.contains.missing",
+                "msg": "Must contain %1.",
+                "args": ["@"],
+                "human_readable": "Must contain '@'"
+            }]
+        },
+        {
+            "tag": "role",
+            "invalids": [{
+                "code": "accord.either.not_allowed",
+                "msg": "Must be one of %1.",
+                "args": [["user", "admin"]],
+                "human_readable": "Must be one of user, admin"
+            }]
+        },
+        {
+            "tag": "age",
+            "invalids": [{
+                "code": "accord.range.out_of_range",
+                "msg": "Must be in the range %1..%2.",
+                "args": [{ "name": "min", "value": 12 }, { "name": "max", "value": 127 }],
+                "human_readable": "Must be between 12 and 127"
+            }]
+        }
+    ]));
+
+    // round-trip
+    let body = serde_json::to_string(&error).unwrap();
+    let received: MultipleError = serde_json::from_str(&body).unwrap();
+    assert_eq!(serde_json::to_value(&received).unwrap(), json);
+    assert_eq!(received.to_string(), error.to_string());
+    assert_eq!(received.0[2].invalids[0].args,
+               vec![Arg::named("min", &12), Arg::named("max", &127)]);
+
+    // and re-localized downstream
+    let mut catalog = Catalog::new();
+    catalog.insert("da", "accord.contains.missing", "Skal indeholde %1.");
+    let localized = catalog.localize(&received, "da");
+    assert_eq!(localized.0[0].invalids[0].human_readable, "Skal indeholde @.");
+
+    // errors from before codes were added
+    let error: Error = serde_json::from_str(r#"[{
+        "msg": "Must contain %1.",
+        "args": ["@"],
+        "human_readable": "Must contain '@'"
+    }]"#).unwrap();
+    assert_eq!(error.0[0].code, "");
+    assert_eq!(error.0[0].args, vec![Arg::Str("@".to_string())]);
+}
@@ -0,0 +1,113 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate accord_derive;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use accord::{Accord, FailFast, Invalid, Result as AccordResult, Settings, ValidatorResult};
+use accord::validators::{contains, each, max, min, nested};
+
+#[derive(Accord)]
+struct Account {
+    #[accord(min(3), max(16))]
+    pub name: String,
+    #[accord(contains("@"), contains("."), max(5))]
+    pub email: String,
+}
+
+struct Login {
+    pub email: String,
+    pub password: String,
+}
+
+impl Accord for Login {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "email" => self.email => [contains("@"), contains(".")],
+            "password" => self.password => [min(8), contains("!")]
+        }
+    }
+}
+
+static CHECKED: AtomicUsize = AtomicUsize::new(0);
+
+fn in_stock(_: &String) -> ValidatorResult {
+    CHECKED.fetch_add(1, Ordering::SeqCst);
+    Err(Invalid {
+        code: "order.sku.out_of_stock".to_string(),
+        msg: "Out of stock.".to_string(),
+        args: vec![],
+        human_readable: "Out of stock".to_string(),
+    })
+}
+
+#[derive(Accord)]
+struct Line {
+    #[accord(in_stock)]
+    pub sku: String,
+}
+
+#[derive(Accord)]
+struct Order {
+    #[accord(each(vec![nested()]))]
+    pub lines: Vec<Line>,
+}
+
+fn count(result: AccordResult) -> usize {
+    result.unwrap_err().0.iter().map(|m| m.invalids.len()).sum()
+}
+
+#[test]
+fn main() {
+    let account = Account {
+        name: "Te".to_string(),
+        email: "testtest".to_string(),
+    };
+
+    let per_field = Settings { fail_fast: FailFast::PerField, max_errors: None };
+    let global = Settings { fail_fast: FailFast::Global, max_errors: None };
+    let limited = Settings { fail_fast: FailFast::Never, max_errors: Some(2) };
+
+    assert_eq!(count(account.validate()), 4);
+    assert_eq!(count(account.validate_with_settings(&Settings::default())), 4);
+
+    let errors = account.validate_with_settings(&per_field).unwrap_err().0;
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[1].tag, "email");
+    assert_eq!(errors[1].invalids.len(), 1);
+
+    let errors = account.validate_with_settings(&global).unwrap_err().0;
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].tag, "name");
+
+    let errors = account.validate_with_settings(&limited).unwrap_err().0;
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[1].invalids.len(), 1);
+
+    // implementations without settings support still honour them
+    let login = Login {
+        email: "test".to_string(),
+        password: "test".to_string(),
+    };
+
+    assert_eq!(count(login.validate()), 4);
+    assert_eq!(count(login.validate_with_settings(&per_field)), 2);
+    assert_eq!(count(login.validate_with_settings(&global)), 1);
+    assert_eq!(count(login.validate_with_settings(&limited)), 2);
+
+    // settings are passed on to nested data, so it stops early as well
+    let order = Order {
+        lines: (0..10).map(|i| Line { sku: i.to_string() }).collect(),
+    };
+
+    assert_eq!(count(order.validate()), 10);
+    assert_eq!(CHECKED.swap(0, Ordering::SeqCst), 10);
+
+    let errors = order.validate_with_settings(&global).unwrap_err().0;
+    assert_eq!(errors[0].tag, "lines[0].sku");
+    assert_eq!(CHECKED.swap(0, Ordering::SeqCst), 1);
+
+    assert_eq!(count(order.validate_with_settings(&limited)), 2);
+    assert_eq!(CHECKED.swap(0, Ordering::SeqCst), 2);
+}
@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate accord;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+use accord::{Accord, Result as AccordResult};
+use accord::serde::{DeserializeError, Validated};
+use accord::validators::{contains, max, min, nested};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Address {
+    pub zip: String,
+}
+
+impl Accord for Address {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "zip" => self.zip => [min(4)]
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Account {
+    pub name: String,
+    pub email: String,
+    pub address: Address,
+}
+
+impl Accord for Account {
+    fn validate(&self) -> AccordResult {
+        rules!{
+            "name" => self.name => [max(16)],
+            "email" => self.email => [contains("@")],
+            "address" => self.address => [nested()]
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Message {
+    pub account: Validated<Account>,
+}
+
+#[test]
+fn main() {
+    let okay = r#"{"name": "test", "email": "test@test.test", "address": {"zip": "1234"}}"#;
+    let error = r#"{"name": "test", "email": "test", "address": {"zip": "12"}}"#;
+
+    let account: Validated<Account> = serde_json::from_str(okay).unwrap();
+    assert_eq!(account.name, "test");
+    assert_eq!(serde_json::to_string(&account).unwrap(), okay.replace(": ", ":").replace(", ", ","));
+
+    let message = serde_json::from_str::<Validated<Account>>(error).unwrap_err().to_string();
+    assert!(message.starts_with("email: Must contain '@'\naddress.zip: Must contain more than 4 characters"));
+
+    // also as part of a bigger message
+    let message: Message = serde_json::from_str(&format!(r#"{{"account": {}}}"#, okay)).unwrap();
+    assert_eq!(message.account.address.zip, "1234");
+    let message: Result<Message, _> = serde_json::from_str(&format!(r#"{{"account": {}}}"#, error));
+    assert!(message.unwrap_err().to_string().starts_with("email: Must contain '@'"));
+
+    // the structured errors
+    let mut deserializer = serde_json::Deserializer::from_str(error);
+    match Validated::<Account>::deserialize_validated(&mut deserializer) {
+        Err(DeserializeError::Invalid(e)) => {
+            assert_eq!(e.0[0].tag, "email");
+            assert_eq!(e.0[1].tag, "address.zip");
+        }
+        _ => panic!("expected the errors of validating the account"),
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#"{"name": "test"}"#);
+    match Validated::<Account>::deserialize_validated(&mut deserializer) {
+        Err(DeserializeError::Deserialize(e)) => assert!(e.is_data()),
+        _ => panic!("expected a deserialization error"),
+    }
+
+    let account = Account {
+        name: "test".to_string(),
+        email: "test".to_string(),
+        address: Address { zip: "1234".to_string() },
+    };
+    assert!(Validated::new(account).is_err());
+}